@@ -1,6 +1,7 @@
 use std::io::{BufRead, BufReader};
 #[allow(unused_imports)]
 use tracing::{debug, event_enabled, info, Level};
+use utils::{parse_field, ParseError, RunContext};
 
 pub type ResultType = u64;
 
@@ -19,27 +20,33 @@ impl Solution {
 
 #[allow(unused_variables, unused_mut)]
 impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
-    type Error = std::io::Error;
+    type Error = ParseError;
 
     fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
         let mut solution = Self::default();
         for (id, line) in reader.lines().map_while(Result::ok).enumerate() {
-            let direction = line.trim().chars().next().unwrap();
-            let count = line
-                .trim()
-                .chars()
-                .skip(1)
-                .collect::<String>()
-                .parse()
-                .unwrap();
+            let line = line.trim();
+            let direction = match line.chars().next() {
+                Some(c @ ('L' | 'R')) => c,
+                _ => {
+                    return Err(ParseError::in_line(
+                        id,
+                        line,
+                        line,
+                        "expected direction 'L' or 'R'",
+                    ))
+                }
+            };
+            let count = parse_field(id, line, &line[1..])?;
             solution.add_rotation(direction, count);
         }
         Ok(solution)
     }
 }
 impl utils::Solution for Solution {
-    type Result = anyhow::Result<ResultType>;
-    fn analyse(&mut self, _is_full: bool) {
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {
         self.num_zeros = 0;
         self.num_end_zeros = 0;
         let mut position = 50;
@@ -68,11 +75,11 @@ impl utils::Solution for Solution {
         }
     }
 
-    fn answer_part1(&self, _is_full: bool) -> Self::Result {
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
         Ok(self.num_end_zeros as ResultType)
     }
 
-    fn answer_part2(&self, _is_full: bool) -> Self::Result {
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
         Ok(self.num_zeros as ResultType)
     }
 }
@@ -4,6 +4,7 @@ use std::{
 };
 #[allow(unused_imports)]
 use tracing::{debug, event_enabled, info, Level};
+use utils::{parse_field, ParseError, RunContext};
 
 pub type ResultType = u64;
 
@@ -25,47 +26,90 @@ impl Solution {
 
 #[allow(unused_variables, unused_mut)]
 impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
-    type Error = std::io::Error;
+    type Error = ParseError;
 
     fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
         let mut solution = Self::default();
         for (id, line) in reader.lines().map_while(Result::ok).enumerate() {
-            let line = line.trim();
             let mut machine = Machine::default();
-            for part in line.split(' ') {
-                match part.chars().next().unwrap() {
+            let mut button_fields = Vec::new();
+            let mut joltage_field = None;
+            for part in line.trim().split(' ') {
+                let expected = || {
+                    ParseError::in_line(
+                        id,
+                        &line,
+                        part,
+                        "expected '[lights]', '(buttons)' or '{joltage}'",
+                    )
+                };
+                let open = part.chars().next().ok_or_else(expected)?;
+                let close = match open {
+                    '[' => ']',
+                    '(' => ')',
+                    '{' => '}',
+                    _ => return Err(expected()),
+                };
+                // Strip the surrounding brackets
+                let contents = part
+                    .strip_prefix(open)
+                    .and_then(|p| p.strip_suffix(close))
+                    .ok_or_else(|| {
+                        ParseError::in_line(id, &line, part, format!("expected closing '{close}'"))
+                    })?;
+                let values = || {
+                    contents
+                        .split(',')
+                        .map(|v| parse_field::<usize>(id, &line, v).map(|value| (value, v)))
+                        .collect::<Result<Vec<_>, _>>()
+                };
+                match open {
                     '[' => {
-                        machine.lights = part
-                            .chars()
-                            .skip(1)
-                            .take(part.len() - 2)
-                            .collect::<String>()
+                        if let Some((i, c)) = contents
+                            .char_indices()
+                            .find(|(_, c)| !matches!(c, '.' | '#'))
+                        {
+                            return Err(ParseError::in_line(
+                                id,
+                                &line,
+                                &contents[i..i + c.len_utf8()],
+                                "expected '.' or '#'",
+                            ));
+                        }
+                        machine.lights = contents.to_string();
                     }
                     '(' => {
-                        let mut buttons = part
-                            .chars()
-                            .skip(1)
-                            .take(part.len() - 2)
-                            .collect::<String>()
-                            .split(',')
-                            .map(|v| v.parse::<usize>().unwrap())
-                            .collect::<Vec<_>>();
-                        machine.buttons.push(buttons);
+                        let values = values()?;
+                        machine
+                            .buttons
+                            .push(values.iter().map(|(value, _)| *value).collect());
+                        button_fields.extend(values);
                     }
-                    '{' => {
-                        machine.joltage = part
-                            .chars()
-                            .skip(1)
-                            .take(part.len() - 2)
-                            .collect::<String>()
-                            .split(',')
-                            .map(|v| v.parse::<usize>().unwrap())
-                            .collect::<Vec<_>>();
+                    _ => {
+                        machine.joltage = values()?.into_iter().map(|(value, _)| value).collect();
+                        joltage_field = Some(part);
                     }
-                    c => panic!("unexpected {}", c),
                 }
             }
-            // Implement for problem
+            let lights = machine.lights.len();
+            if let Some((button, field)) =
+                button_fields.iter().find(|(button, _)| *button >= lights)
+            {
+                return Err(ParseError::in_line(
+                    id,
+                    &line,
+                    field,
+                    format!("button {button} is beyond the {lights} lights"),
+                ));
+            }
+            if let Some(field) = joltage_field.filter(|_| machine.joltage.len() != lights) {
+                return Err(ParseError::in_line(
+                    id,
+                    &line,
+                    field,
+                    format!("expected {lights} joltage values, one per light"),
+                ));
+            }
             solution.add_machine(machine);
         }
         Ok(solution)
@@ -73,10 +117,11 @@ impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
 }
 
 impl utils::Solution for Solution {
-    type Result = anyhow::Result<ResultType>;
-    fn analyse(&mut self, _is_full: bool) {}
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
 
-    fn answer_part1(&self, _is_full: bool) -> Self::Result {
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
         let mut total = 0;
         for machine in &self.machines {
             let presses = fewest_presses_lights(machine);
@@ -86,7 +131,7 @@ impl utils::Solution for Solution {
         Ok(total as ResultType)
     }
 
-    fn answer_part2(&self, _is_full: bool) -> Self::Result {
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
         let mut total = 0;
         for machine in self.machines.iter().take(10000) {
             let presses = solve_p2(machine);
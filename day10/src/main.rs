@@ -1,8 +1,8 @@
 use anyhow::Result;
-use day10::{ResultType, Solution};
+use day10::Solution;
 
 fn main() -> Result<()> {
-    utils::log_init();
+    let _log = utils::log_init();
 
-    utils::run::<Solution, ResultType>(&["sample"], &["full"])
+    utils::run_discovered::<Solution>()
 }
@@ -5,6 +5,7 @@ use std::{
 };
 #[allow(unused_imports)]
 use tracing::{debug, event_enabled, info, Level};
+use utils::{ParseError, RunContext};
 
 pub type ResultType = u64;
 
@@ -20,13 +21,14 @@ impl Solution {
 
 #[allow(unused_variables, unused_mut)]
 impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
-    type Error = std::io::Error;
+    type Error = ParseError;
 
     fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
         let mut solution = Self::default();
         for (id, line) in reader.lines().map_while(Result::ok).enumerate() {
-            // Implement for problem
-            let (source, targets) = line.split_once(':').unwrap();
+            let (source, targets) = line.split_once(':').ok_or_else(|| {
+                ParseError::in_line(id, &line, &line, "expected 'device: outputs...'")
+            })?;
             let source = source.trim().to_string();
             let targets = targets
                 .split(' ')
@@ -39,21 +41,24 @@ impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
     }
 }
 impl utils::Solution for Solution {
-    type Result = anyhow::Result<ResultType>;
-    fn analyse(&mut self, _is_full: bool) {}
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
 
-    fn answer_part1(&self, is_full: bool) -> Self::Result {
-        let r = if is_full {
-            num_paths_part1(&self.devices, "you", "out")
-        } else {
+    fn answer_part1(&self, ctx: &RunContext) -> Self::Part1 {
+        let r = if ctx.param_or("skip_part1", false)? {
             0
+        } else {
+            num_paths_part1(&self.devices, "you", "out")
         };
         // Implement for problem
         Ok(r as ResultType)
     }
 
-    fn answer_part2(&self, is_full: bool) -> Self::Result {
-        let r = num_paths_part2(&self.devices, is_full, "svr".to_string(), false, false);
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
+        // The cache ignores `devices`, so start afresh for each input
+        memoized_flush_num_paths_part2();
+        let r = num_paths_part2(&self.devices, "svr".to_string(), false, false);
         // Implement for problem
         Ok(r as ResultType)
     }
@@ -79,7 +84,6 @@ fn num_paths_part1(devices: &HashMap<String, Vec<String>>, current: &str, target
 #[memoize(Ignore:devices)]
 fn num_paths_part2(
     devices: &HashMap<String, Vec<String>>,
-    is_full: bool,
     current: String,
     seen_dac: bool,
     seen_fft: bool,
@@ -98,7 +102,6 @@ fn num_paths_part2(
                 for next in targets {
                     count += num_paths_part2(
                         devices,
-                        is_full,
                         next.to_owned(),
                         seen_dac || current == "dac",
                         seen_fft || current == "fft",
@@ -3,7 +3,10 @@ use std::io::{BufRead, BufReader};
 use tracing::enabled;
 #[allow(unused_imports)]
 use tracing::{debug, event_enabled, info, Level};
-use utils::grid::Matrix;
+use utils::{
+    grid::{Matrix, ParseGrid, Transform},
+    parse_field, CancellationToken, Cancelled, ParseError, RunContext,
+};
 
 pub type ResultType = u64;
 
@@ -28,32 +31,12 @@ impl Debug for Present {
     }
 }
 impl Present {
-    fn rotate(&self) -> Self {
-        let mut rotated_shape = Matrix::new();
-        let (maxx, maxy) = self.shape.dimensions();
-        for y in 0..=maxy {
-            for x in 0..=maxx {
-                let value = self.shape.get(x, y).unwrap();
-                rotated_shape.set(maxy - y, x, *value);
-            }
-        }
-        Self {
-            shape: rotated_shape,
-        }
-    }
-
-    fn flip(&self) -> Self {
-        let mut flipped_shape = Matrix::new();
-        let (maxx, maxy) = self.shape.dimensions();
-        for y in 0..=maxy {
-            for x in 0..=maxx {
-                let value = self.shape.get(x, y).unwrap();
-                flipped_shape.set(x, maxy - y, *value);
-            }
-        }
-        Self {
-            shape: flipped_shape,
-        }
+    /// Every distinct rotation and reflection of the shape
+    fn variants(&self) -> impl Iterator<Item = Self> {
+        self.shape
+            .variants()
+            .into_iter()
+            .map(|shape| Self { shape })
     }
 }
 
@@ -70,13 +53,9 @@ impl Debug for Solution {
 
 impl Solution {
     pub(crate) fn add_present(&mut self, v: &[String]) {
-        let mut shape = Matrix::new();
-        for (y, row) in v.iter().enumerate() {
-            for (x, c) in row.chars().enumerate() {
-                shape.set(x as isize, y as isize, c);
-            }
-        }
-        self.presents.push(Present { shape });
+        self.presents.push(Present {
+            shape: Matrix::from_lines(v),
+        });
     }
     pub(crate) fn add_region(&mut self, width: isize, height: isize, shapes: Vec<u64>) {
         self.regions.push(Region {
@@ -89,14 +68,13 @@ impl Solution {
 
 #[allow(unused_variables, unused_mut)]
 impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
-    type Error = std::io::Error;
+    type Error = ParseError;
 
     fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
         let mut solution = Self::default();
         let mut shape = Vec::new();
-        for (id, line) in reader.lines().map_while(Result::ok).enumerate() {
-            // Implement for problem
-            let line = line.trim();
+        for (id, full_line) in reader.lines().map_while(Result::ok).enumerate() {
+            let line = full_line.trim();
             if line.is_empty() {
                 if !shape.is_empty() {
                     solution.add_present(&shape);
@@ -112,13 +90,15 @@ impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
                     if rhs.is_empty() {
                         // Id - ignore
                     } else {
-                        let (width, height) = lhs.split_once('x').unwrap();
-                        let width = width.parse().unwrap();
-                        let height = height.parse().unwrap();
+                        let (width, height) = lhs.split_once('x').ok_or_else(|| {
+                            ParseError::in_line(id, &full_line, lhs, "expected 'WxH'")
+                        })?;
+                        let width = parse_field(id, &full_line, width)?;
+                        let height = parse_field(id, &full_line, height)?;
                         let quantity = rhs
                             .split(' ')
-                            .map(|s| s.parse::<u64>().unwrap())
-                            .collect::<Vec<_>>();
+                            .map(|s| parse_field::<u64>(id, &full_line, s))
+                            .collect::<Result<Vec<_>, _>>()?;
                         solution.add_region(width, height, quantity);
                     }
                 }
@@ -128,10 +108,11 @@ impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
     }
 }
 impl utils::Solution for Solution {
-    type Result = anyhow::Result<ResultType>;
-    fn analyse(&mut self, _is_full: bool) {}
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
 
-    fn answer_part1(&self, _is_full: bool) -> Self::Result {
+    fn answer_part1(&self, ctx: &RunContext) -> Self::Part1 {
         let mut r = 0;
         let mut maybe_r = 0;
         for region in &self.regions {
@@ -142,7 +123,7 @@ impl utils::Solution for Solution {
         for region in &self.regions {
             if may_fit_presents(region, &self.presents) {
                 debug!("checking region {:?}", region);
-                if can_fit_presents(region, &self.presents) {
+                if can_fit_presents(region, &self.presents, ctx.cancellation())? {
                     debug!("presents fit in region {:?}", region);
                     r += 1;
                 }
@@ -153,7 +134,7 @@ impl utils::Solution for Solution {
         Ok(r)
     }
 
-    fn answer_part2(&self, _is_full: bool) -> Self::Result {
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
         // Implement for problem
         Ok(0)
     }
@@ -182,36 +163,43 @@ fn may_fit_presents(region: &Region, presents: &[Present]) -> bool {
     );
     (area - total_present_area) > 0
 }
-fn can_fit_presents(region: &Region, presents: &[Present]) -> bool {
+fn can_fit_presents(
+    region: &Region,
+    presents: &[Present],
+    cancel: &CancellationToken,
+) -> Result<bool, Cancelled> {
     let mut space = Matrix::new();
     for y in 0..region.height {
         for x in 0..region.width {
             space.set(x, y, '.');
         }
     }
-    presents_fit(space, &region.present_counts, presents)
+    presents_fit(space, &region.present_counts, presents, cancel)
 }
 
-fn presents_fit(space: Matrix<char>, remaining_counts: &[u64], presents: &[Present]) -> bool {
+fn presents_fit(
+    space: Matrix<char>,
+    remaining_counts: &[u64],
+    presents: &[Present],
+    cancel: &CancellationToken,
+) -> Result<bool, Cancelled> {
+    cancel.check()?;
     let r = remaining_counts.iter().enumerate().find(|(_i, v)| **v > 0);
     match r {
         None => {
             if enabled!(Level::DEBUG) {
                 space.display();
             }
-            true
+            Ok(true)
         }
         Some((idx, _count)) => {
             // Find all possible rotations / flips / positions :(
             let present = presents.get(idx).unwrap();
-            let mut present = present.to_owned();
-            for rotate in 1..=4 {
-                present = present.rotate();
+            for (variant, present) in present.variants().enumerate() {
                 if enabled!(Level::DEBUG) {
-                    info!("rotate {}", rotate);
+                    info!("variant {}", variant);
                     present.shape.display();
                 }
-                // No flip
                 for (x, y) in get_all_permitted(&space, &present) {
                     // Mark populated cells
                     let mut new_space = space.clone();
@@ -231,17 +219,12 @@ fn presents_fit(space: Matrix<char>, remaining_counts: &[u64], presents: &[Prese
                     let mut new_remaining_counts = remaining_counts.to_vec();
                     new_remaining_counts[idx] -= 1;
                     // Does remainder fit?
-                    if presents_fit(new_space, &new_remaining_counts, presents) {
-                        return true;
+                    if presents_fit(new_space, &new_remaining_counts, presents, cancel)? {
+                        return Ok(true);
                     }
                 }
-                // Flip
-                present = present.flip();
-
-                // Flip back for next loop
-                present = present.flip();
             }
-            false
+            Ok(false)
         }
     }
 }
@@ -4,43 +4,46 @@ use std::{
 };
 #[allow(unused_imports)]
 use tracing::{debug, event_enabled, info, Level};
+use utils::{parse_field, ParseError, RunContext};
 
 pub type ResultType = u64;
 
 #[derive(Debug, Default)]
 pub struct Solution {
-    ranges: Vec<(String, String)>,
+    ranges: Vec<(u64, u64)>,
 }
 impl Solution {
-    pub fn add_range(&mut self, start: &str, end: &str) {
-        self.ranges.push((start.to_owned(), end.to_owned()));
+    pub fn add_range(&mut self, start: u64, end: u64) {
+        self.ranges.push((start, end));
     }
 }
 
 #[allow(unused_variables, unused_mut)]
 impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
-    type Error = std::io::Error;
+    type Error = ParseError;
 
     fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
         let mut solution = Self::default();
         for (id, line) in reader.lines().map_while(Result::ok).enumerate() {
             for p in line.trim().split(',') {
-                let (start, end) = p.split_once('-').unwrap();
-                solution.add_range(start, end);
+                let (start, end) = p
+                    .split_once('-')
+                    .ok_or_else(|| ParseError::in_line(id, &line, p, "expected 'start-end'"))?;
+                solution.add_range(parse_field(id, &line, start)?, parse_field(id, &line, end)?);
             }
         }
         Ok(solution)
     }
 }
 impl utils::Solution for Solution {
-    type Result = anyhow::Result<ResultType>;
-    fn analyse(&mut self, _is_full: bool) {}
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
 
-    fn answer_part1(&self, _is_full: bool) -> Self::Result {
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
         let mut total = 0;
-        for (start, end) in &self.ranges {
-            let start_val = start.parse::<u64>().unwrap();
-            let end_val = end.parse::<u64>().unwrap();
+        for &(start_val, end_val) in &self.ranges {
+            let start = start_val.to_string();
             let mut s = start[..start.len() / 2].parse::<u64>().unwrap_or_default();
             loop {
                 let probe = format!("{}{}", s, s).parse::<u64>().unwrap();
@@ -57,13 +60,14 @@ impl utils::Solution for Solution {
         Ok(total)
     }
 
-    fn answer_part2(&self, _is_full: bool) -> Self::Result {
+    fn answer_part2(&self, ctx: &RunContext) -> Self::Part2 {
+        let cancel = ctx.cancellation();
         let mut total = 0;
-        for (start, end) in &self.ranges {
-            let start_val = start.parse::<u64>().unwrap();
-            let end_val = end.parse::<u64>().unwrap();
+        for &(start_val, end_val) in &self.ranges {
+            let (start, end) = (start_val.to_string(), end_val.to_string());
             let mut values = HashSet::new();
             for val in start_val..=end_val {
+                cancel.check()?;
                 let val_str = format!("{}", val);
                 for repeat_len in 1..=end.len() / 2 {
                     for repeat in start.len() / repeat_len..=end.len() / repeat_len {
@@ -4,6 +4,7 @@ use std::{
 };
 #[allow(unused_imports)]
 use tracing::{debug, event_enabled, info, Level};
+use utils::{ParseError, RunContext};
 
 pub type ResultType = u64;
 
@@ -19,22 +20,31 @@ impl Solution {
 
 #[allow(unused_variables, unused_mut)]
 impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
-    type Error = std::io::Error;
+    type Error = ParseError;
 
     fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
         let mut solution = Self::default();
         for (id, line) in reader.lines().map_while(Result::ok).enumerate() {
-            solution.add_bank(line.trim());
-            // Implement for problem
+            let bank = line.trim();
+            if let Some((pos, c)) = bank.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+                return Err(ParseError::in_line(
+                    id,
+                    &line,
+                    &bank[pos..pos + c.len_utf8()],
+                    "expected a digit",
+                ));
+            }
+            solution.add_bank(bank);
         }
         Ok(solution)
     }
 }
 impl utils::Solution for Solution {
-    type Result = anyhow::Result<ResultType>;
-    fn analyse(&mut self, _is_full: bool) {}
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
 
-    fn answer_part1(&self, _is_full: bool) -> Self::Result {
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
         let r: i64 = self
             .banks
             .iter()
@@ -51,7 +61,7 @@ impl utils::Solution for Solution {
         Ok(r as ResultType)
     }
 
-    fn answer_part2(&self, _is_full: bool) -> Self::Result {
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
         let r: i64 = self
             .banks
             .iter()
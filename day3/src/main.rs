@@ -1,8 +1,8 @@
 use anyhow::Result;
-use day3::{ResultType, Solution};
+use day3::Solution;
 
 fn main() -> Result<()> {
-    utils::log_init();
+    let _log = utils::log_init();
 
-    utils::run::<Solution, ResultType>(&["sample"], &["full"])
+    utils::run_discovered::<Solution>()
 }
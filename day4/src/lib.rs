@@ -1,7 +1,8 @@
-use std::io::{BufRead, BufReader};
+use std::io::BufReader;
 #[allow(unused_imports)]
 use tracing::{debug, event_enabled, info, Level};
-use utils::grid::Matrix;
+use utils::grid::{Grid, Matrix, ParseGrid};
+use utils::{point::Point, ParseError, RunContext};
 
 pub type ResultType = u64;
 
@@ -9,38 +10,29 @@ pub type ResultType = u64;
 pub struct Solution {
     matrix: Matrix<char>,
 }
-impl Solution {
-    pub fn set_matrix(&mut self, x: usize, y: usize, c: char) {
-        self.matrix.set(x as isize, y as isize, c);
-    }
-}
 
 #[allow(unused_variables, unused_mut)]
 impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
-    type Error = std::io::Error;
+    type Error = ParseError;
 
     fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
-        let mut solution = Self::default();
-        for (y, line) in reader.lines().map_while(Result::ok).enumerate() {
-            // Implement for problem
-            for (x, c) in line.trim().chars().enumerate() {
-                solution.set_matrix(x, y, c);
-            }
-        }
-        Ok(solution)
+        Ok(Self {
+            matrix: Matrix::from_reader(reader),
+        })
     }
 }
 impl utils::Solution for Solution {
-    type Result = anyhow::Result<ResultType>;
-    fn analyse(&mut self, _is_full: bool) {}
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
 
-    fn answer_part1(&self, _is_full: bool) -> Self::Result {
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
         let count = get_removable(&self.matrix).len();
         // Implement for problem
         Ok(count as ResultType)
     }
 
-    fn answer_part2(&self, _is_full: bool) -> Self::Result {
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
         let mut removed = 0;
         let mut matrix = self.matrix.clone();
         loop {
@@ -49,8 +41,8 @@ impl utils::Solution for Solution {
                 break;
             }
             removed += removable.len();
-            for (x, y) in removable {
-                matrix.set(x, y, '.');
+            for point in removable {
+                Grid::set(&mut matrix, point, '.');
             }
         }
         // Implement for problem
@@ -58,27 +50,10 @@ impl utils::Solution for Solution {
     }
 }
 
-fn get_removable(matrix: &Matrix<char>) -> Vec<(isize, isize)> {
-    let mut removable = Vec::new();
-    for y in matrix.min_y()..=matrix.max_y() {
-        for x in matrix.min_x()..=matrix.max_x() {
-            if let Some('@') = matrix.get(x, y) {
-                let mut neighbours = 0;
-                for dy in -1..=1 {
-                    for dx in -1..=1 {
-                        if dx == 0 && dy == 0 {
-                            continue;
-                        }
-                        if let Some('@') = matrix.get(x + dx, y + dy) {
-                            neighbours += 1;
-                        }
-                    }
-                }
-                if neighbours < 4 {
-                    removable.push((x, y));
-                }
-            }
-        }
-    }
-    removable
+fn get_removable(grid: &impl Grid<Item = char>) -> Vec<Point<isize>> {
+    grid.cells()
+        .filter(|(_, c)| **c == '@')
+        .filter(|(point, _)| grid.neighbours(*point).filter(|(_, c)| **c == '@').count() < 4)
+        .map(|(point, _)| point)
+        .collect()
 }
@@ -1,6 +1,7 @@
 use std::io::{BufRead, BufReader};
 #[allow(unused_imports)]
 use tracing::{debug, event_enabled, info, Level};
+use utils::{parse_field, ParseError, RunContext};
 
 pub type ResultType = u64;
 
@@ -20,24 +21,23 @@ impl Solution {
 
 #[allow(unused_variables, unused_mut)]
 impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
-    type Error = std::io::Error;
+    type Error = ParseError;
 
     fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
         let mut solution = Self::default();
         for (id, line) in reader.lines().map_while(Result::ok).enumerate() {
-            // Implement for problem
             let line = line.trim();
             if line.is_empty() {
                 continue;
             }
             match line.split_once('-') {
                 Some((a, b)) => {
-                    let start = a.parse().unwrap();
-                    let end = b.parse().unwrap();
+                    let start = parse_field(id, line, a)?;
+                    let end = parse_field(id, line, b)?;
                     solution.add_freshlist(start, end);
                 }
                 None => {
-                    let ingredient = line.parse().unwrap();
+                    let ingredient = parse_field(id, line, line)?;
                     solution.add_ingredient(ingredient);
                 }
             }
@@ -46,10 +46,11 @@ impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
     }
 }
 impl utils::Solution for Solution {
-    type Result = anyhow::Result<ResultType>;
-    fn analyse(&mut self, _is_full: bool) {}
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
 
-    fn answer_part1(&self, _is_full: bool) -> Self::Result {
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
         let mut count = 0;
         for ingredient in &self.ingredients {
             if is_fresh(*ingredient, &self.freshlist) {
@@ -60,7 +61,7 @@ impl utils::Solution for Solution {
         Ok(count)
     }
 
-    fn answer_part2(&self, _is_full: bool) -> Self::Result {
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
         let mut ranges = Vec::new();
         for (start, end) in &self.freshlist {
             ranges = add_range(&ranges, *start, *end);
@@ -1,125 +1,197 @@
-use std::{
-    collections::HashSet,
-    io::{BufRead, BufReader},
-};
+use std::io::{BufRead, BufReader};
 #[allow(unused_imports)]
 use tracing::{debug, event_enabled, info, Level};
+use utils::{parse_field, ParseError, RunContext};
 
 pub type ResultType = u64;
 
-#[derive(Debug, Default)]
-pub struct Solution {
-    part1_lines: Vec<Vec<String>>,
-    part2_lines: Vec<String>,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+impl Operation {
+    fn apply(&self, vals: impl IntoIterator<Item = ResultType>) -> ResultType {
+        match self {
+            Operation::Add => vals.into_iter().sum(),
+            Operation::Multiply => vals.into_iter().product(),
+        }
+    }
 }
-impl Solution {
-    pub fn add_line(&mut self, part1_line: Vec<String>, part2_line: String) {
-        self.part1_lines.push(part1_line);
-        self.part2_lines.push(part2_line);
+impl TryFrom<char> for Operation {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '+' => Ok(Operation::Add),
+            '*' => Ok(Operation::Multiply),
+            _ => Err(format!("unknown operation '{c}', expected '+' or '*'")),
+        }
     }
 }
 
+#[derive(Debug, Default)]
+pub struct Solution {
+    /// Numbers read along each row
+    rows: Vec<Vec<ResultType>>,
+    operations: Vec<Operation>,
+    /// Number read down each char column (right to left), with the operation below it
+    columns: Vec<(Option<ResultType>, Option<Operation>)>,
+}
+
 #[allow(unused_variables, unused_mut)]
 impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
-    type Error = std::io::Error;
+    type Error = ParseError;
 
     fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
-        let r = regex::Regex::new(r"\s+").unwrap();
+        let mut lines = reader.lines().map_while(Result::ok).collect::<Vec<_>>();
+        let Some(operator_line) = lines.pop() else {
+            return Ok(Self::default());
+        };
+        let operator_id = lines.len();
+        if lines.is_empty() {
+            return Err(ParseError::in_line(
+                operator_id,
+                &operator_line,
+                &operator_line,
+                "expected rows of numbers above the operations",
+            ));
+        }
+        let width = operator_line.chars().count();
         let mut solution = Self::default();
-        for (id, line) in reader.lines().map_while(Result::ok).enumerate() {
-            let p = r
-                .split(line.trim())
-                .map(|s| s.to_string())
-                .collect::<Vec<_>>();
-            solution.add_line(p, line.to_string());
+        for (id, line) in lines.iter().enumerate() {
+            if line.chars().count() != width {
+                return Err(ParseError::in_line(
+                    id,
+                    line,
+                    line,
+                    format!("expected {width} columns, as on the operations line"),
+                ));
+            }
+            let row = line
+                .split_whitespace()
+                .map(|v| parse_field(id, line, v))
+                .collect::<Result<Vec<_>, _>>()?;
+            solution.rows.push(row);
+        }
+        let mut operations = Vec::new();
+        let mut column_operations = Vec::new();
+        for (i, c) in operator_line.char_indices() {
+            let operation = match c {
+                ' ' => None,
+                c => Some(Operation::try_from(c).map_err(|e| {
+                    ParseError::in_line(
+                        operator_id,
+                        &operator_line,
+                        &operator_line[i..i + c.len_utf8()],
+                        e,
+                    )
+                })?),
+            };
+            operations.extend(operation);
+            column_operations.push(operation);
+        }
+        for (id, row) in solution.rows.iter().enumerate() {
+            if row.len() != operations.len() {
+                return Err(ParseError::in_line(
+                    id,
+                    &lines[id],
+                    &lines[id],
+                    format!("expected {} numbers, one per operation", operations.len()),
+                ));
+            }
+        }
+        solution.operations = operations;
+        let grid = lines
+            .iter()
+            .map(|line| line.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        for (i, operation) in column_operations.into_iter().enumerate().rev() {
+            let digits = grid
+                .iter()
+                .map(|line| line[i])
+                .filter(|c| *c != ' ')
+                .collect::<String>();
+            let val = if digits.is_empty() {
+                None
+            } else {
+                // Every digit has already been parsed as part of a row
+                Some(
+                    digits
+                        .parse::<ResultType>()
+                        .map_err(|e| ParseError::in_line(0, &lines[0], &digits, e))?,
+                )
+            };
+            solution.columns.push((val, operation));
         }
         Ok(solution)
     }
 }
 impl utils::Solution for Solution {
-    type Result = anyhow::Result<ResultType>;
-    fn analyse(&mut self, _is_full: bool) {
-        assert_eq!(
-            1,
-            self.part1_lines
-                .iter()
-                .map(|line| line.len())
-                .collect::<HashSet<_>>()
-                .len()
-        );
-        assert_eq!(
-            1,
-            self.part2_lines
-                .iter()
-                .map(|line| line.chars().count())
-                .collect::<HashSet<_>>()
-                .len()
-        )
-    }
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
 
-    fn answer_part1(&self, _is_full: bool) -> Self::Result {
-        let mut lines = self.part1_lines.clone();
-        let mut total = lines[0]
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
+        let total = self
+            .operations
             .iter()
-            .map(|v| v.parse::<ResultType>().unwrap())
+            .enumerate()
+            .map(|(i, operation)| operation.apply(self.rows.iter().map(|row| row[i])))
             .collect::<Vec<_>>();
-        let operations = lines.pop().unwrap();
-        for line in lines.iter().skip(1) {
-            for (i, operation) in operations.iter().enumerate() {
-                let val = line[i].parse::<ResultType>().unwrap();
-                if i == 1 {
-                    info!("{} {} {}", val, total[i], i);
-                }
-                match operation.as_str() {
-                    "+" => total[i] += val,
-                    "*" => total[i] *= val,
-                    _ => panic!("Unknown operation {}", operation),
-                };
-            }
-        }
         info!(?total);
         let total = total.iter().sum();
         // Implement for problem
         Ok(total)
     }
 
-    fn answer_part2(&self, _is_full: bool) -> Self::Result {
-        let mut lines = self.part2_lines.clone();
-        let operators = lines.pop().unwrap();
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
         let mut vals = Vec::new();
         let mut total = 0;
-        for i in (0..operators.chars().count()).rev() {
-            let val = lines
-                .iter()
-                .map(|line| line.chars().nth(i).unwrap())
-                .filter(|c| *c != ' ')
-                .collect::<String>();
-            if val.is_empty() {
+        for (val, operation) in &self.columns {
+            let Some(val) = val else {
                 continue;
-            }
+            };
             debug!(val);
-            let val = val.parse::<ResultType>().unwrap();
-            vals.push(val);
-            match operators.chars().nth(i).unwrap() {
-                ' ' => {}
-                '+' => {
-                    total += vals.iter().fold(0, |mut acc, val| {
-                        acc += val;
-                        acc
-                    });
-                    vals.clear();
-                }
-                '*' => {
-                    total += vals.iter().fold(1, |mut acc, val| {
-                        acc *= val;
-                        acc
-                    });
-                    vals.clear();
-                }
-                _ => panic!(),
+            vals.push(*val);
+            if let Some(operation) = operation {
+                total += operation.apply(vals.drain(..));
             }
         }
         // Implement for problem
         Ok(total as ResultType)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use utils::Solution as _;
+
+    const SAMPLE: &str = "123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  
+";
+
+    #[test]
+    fn sample() {
+        let ctx = RunContext::sample(&[]);
+        let solution = utils::load_from_str::<Solution>(SAMPLE).unwrap();
+        assert_eq!(4277556, solution.answer_part1(&ctx).unwrap());
+        assert_eq!(3263827, solution.answer_part2(&ctx).unwrap());
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |input: &str| utils::load_from_str::<Solution>(input).unwrap_err();
+        let e = error("1 2\n3 x\n* +\n");
+        assert_eq!((2, 3, "x"), (e.line, e.column, e.text.as_str()));
+        let e = error("1 2\n3 4\n* -\n");
+        assert_eq!((3, 3, "-"), (e.line, e.column, e.text.as_str()));
+        let e = error("1 2\n3 4 5\n* +\n");
+        assert_eq!(2, e.line);
+        let e = error("1 2 3\n3 4 5\n* +  \n");
+        assert_eq!(1, e.line);
+    }
+}
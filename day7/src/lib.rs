@@ -1,75 +1,55 @@
+use anyhow::Context;
 use memoize::memoize;
-use std::{
-    fmt::Debug,
-    io::{BufRead, BufReader},
-};
+use std::{fmt::Debug, io::BufRead, io::BufReader};
 #[allow(unused_imports)]
 use tracing::{debug, event_enabled, info, Level};
-use utils::grid::Matrix;
+use utils::grid::{Matrix, ParseGrid};
+use utils::{point::Point, ParseError, RunContext};
 
 pub type ResultType = u64;
 
 #[derive(Debug, Default)]
 pub struct Solution {
     manifold: Matrix<char>,
+    start: Option<Point<isize>>,
 }
 impl Solution {
-    pub fn set(&mut self, x: usize, y: usize, c: char) {
-        self.manifold.set(x as isize, y as isize, c);
+    fn start(&self) -> anyhow::Result<Point<isize>> {
+        self.start.context("no start 'S' in the manifold")
     }
 }
 
 #[allow(unused_variables, unused_mut)]
 impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
-    type Error = std::io::Error;
+    type Error = ParseError;
 
     fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
-        let mut solution = Self::default();
-        for (y, line) in reader.lines().map_while(Result::ok).enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                solution.set(x, y, c);
-            }
-        }
-        Ok(solution)
+        let (manifold, markers) =
+            Matrix::parse_lines(reader.lines().map_while(Result::ok), &['S'], |c| c);
+        Ok(Self {
+            manifold,
+            start: markers.first('S'),
+        })
     }
 }
 impl utils::Solution for Solution {
-    type Result = anyhow::Result<ResultType>;
-    fn analyse(&mut self, _is_full: bool) {}
-
-    fn answer_part1(&self, _is_full: bool) -> Self::Result {
-        // Find start
-        let (maxx, maxy) = self.manifold.dimensions();
-        let mut start_x = -1;
-        let mut start_y = -1;
-        for y in 0..=maxy {
-            for x in 0..=maxx {
-                if let Some('S') = self.manifold.get(x, y) {
-                    start_x = x;
-                    start_y = y;
-                }
-            }
-        }
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
 
-        let num_splits = num_splits_path(&self.manifold, start_x, start_y);
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
+        let start = self.start()?;
+        let num_splits = num_splits_path(&self.manifold, start.x(), start.y());
         // Implement for problem
         Ok(num_splits as ResultType)
     }
 
-    fn answer_part2(&self, is_full: bool) -> Self::Result {
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
         // Implement for problem
-        let (maxx, maxy) = self.manifold.dimensions();
-        let mut start_x = -1;
-        let mut start_y = -1;
-        for y in 0..=maxy {
-            for x in 0..=maxx {
-                if let Some('S') = self.manifold.get(x, y) {
-                    start_x = x;
-                    start_y = y;
-                }
-            }
-        }
-        let r = num_timelines(&self.manifold, is_full, start_x, start_y);
+        let start = self.start()?;
+        // The cache ignores `manifold`, so start afresh for each input
+        memoized_flush_num_timelines();
+        let r = num_timelines(&self.manifold, start.x(), start.y());
         Ok(r as ResultType)
     }
 }
@@ -87,15 +67,15 @@ fn num_splits_path(manifold: &Matrix<char>, pos_x: isize, pos_y: isize) -> usize
 }
 
 #[memoize(Ignore:manifold)]
-fn num_timelines(manifold: &Matrix<char>, is_full: bool, pos_x: isize, pos_y: isize) -> isize {
+fn num_timelines(manifold: &Matrix<char>, pos_x: isize, pos_y: isize) -> isize {
     let count = match manifold.get(pos_x, pos_y) {
-        Some('S') => num_timelines(manifold, is_full, pos_x, pos_y + 1),
-        Some('.') => num_timelines(manifold, is_full, pos_x, pos_y + 1),
+        Some('S') => num_timelines(manifold, pos_x, pos_y + 1),
+        Some('.') => num_timelines(manifold, pos_x, pos_y + 1),
         Some('^') => {
             // Went left + right
             [pos_x - 1, pos_x + 1]
                 .iter()
-                .map(|&x| num_timelines(manifold, is_full, x, pos_y + 1))
+                .map(|&x| num_timelines(manifold, x, pos_y + 1))
                 .sum()
         }
         None => 1,
@@ -6,6 +6,7 @@ use std::{
 };
 #[allow(unused_imports)]
 use tracing::{debug, event_enabled, info, Level};
+use utils::{parse_field, ParseError, RunContext};
 
 pub type ResultType = u64;
 
@@ -30,25 +31,27 @@ impl Solution {
 
 #[allow(unused_variables, unused_mut)]
 impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
-    type Error = std::io::Error;
+    type Error = ParseError;
 
     fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
         let mut solution = Self::default();
         for (id, line) in reader.lines().map_while(Result::ok).enumerate() {
-            // Implement for problem
             let (x, y, z) = line
                 .split(',')
-                .map(|v| v.parse::<i64>().unwrap())
+                .map(|v| parse_field::<i64>(id, &line, v))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
                 .collect_tuple()
-                .unwrap();
+                .ok_or_else(|| ParseError::in_line(id, &line, &line, "expected 'x,y,z'"))?;
             solution.add_junctionbox(x, y, z);
         }
         Ok(solution)
     }
 }
 impl utils::Solution for Solution {
-    type Result = anyhow::Result<ResultType>;
-    fn analyse(&mut self, _is_full: bool) {
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {
         self.distances = Vec::new();
         for (i, (x1, y1, z1)) in self.junctionboxes.iter().enumerate() {
             for (j, (x2, y2, z2)) in self.junctionboxes.iter().enumerate().take(i) {
@@ -62,10 +65,10 @@ impl utils::Solution for Solution {
         self.distances.sort_by_key(|(_i, _j, distance)| -distance);
     }
 
-    fn answer_part1(&self, is_full: bool) -> Self::Result {
+    fn answer_part1(&self, ctx: &RunContext) -> Self::Part1 {
         let mut distances = self.distances.clone();
 
-        let limit = if is_full { 1000 } else { 10 };
+        let limit = ctx.param_or("limit", 1000)?;
         let mut connections = HashSet::new();
 
         for _ in 0..limit {
@@ -112,7 +115,7 @@ impl utils::Solution for Solution {
         Ok(r as ResultType)
     }
 
-    fn answer_part2(&self, _is_full: bool) -> Self::Result {
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
         // Put each box in its own group
         let mut group_id =
             self.junctionboxes
@@ -170,3 +173,40 @@ impl utils::Solution for Solution {
         Ok(r as ResultType)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use utils::Solution as _;
+
+    const SAMPLE: &str = "162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+";
+
+    #[test]
+    fn sample() {
+        let ctx = RunContext::sample(&[("limit", "10")]);
+        let mut solution = utils::load_from_str::<Solution>(SAMPLE).unwrap();
+        solution.analyse(&ctx);
+        assert_eq!(40, solution.answer_part1(&ctx).unwrap());
+        assert_eq!(25272, solution.answer_part2(&ctx).unwrap());
+    }
+}
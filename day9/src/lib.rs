@@ -5,7 +5,7 @@ use std::{
 };
 #[allow(unused_imports)]
 use tracing::{debug, event_enabled, info, Level};
-use utils::{grid::Matrix, point::Point};
+use utils::{grid::Matrix, parse_field, point::Point, ParseError, RunContext};
 
 pub type ResultType = u64;
 
@@ -27,23 +27,25 @@ impl Solution {
 
 #[allow(unused_variables, unused_mut)]
 impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
-    type Error = std::io::Error;
+    type Error = ParseError;
 
     fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
         let mut solution = Self::default();
         for (id, line) in reader.lines().map_while(Result::ok).enumerate() {
-            // Implement for problem
-            let (x, y) = line.split_once(',').unwrap();
-            let x = x.parse().unwrap();
-            let y = y.parse().unwrap();
+            let (x, y) = line
+                .split_once(',')
+                .ok_or_else(|| ParseError::in_line(id, &line, &line, "expected 'x,y'"))?;
+            let x = parse_field(id, &line, x)?;
+            let y = parse_field(id, &line, y)?;
             solution.add_tile(x, y);
         }
         Ok(solution)
     }
 }
 impl utils::Solution for Solution {
-    type Result = anyhow::Result<ResultType>;
-    fn analyse(&mut self, _is_full: bool) {
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {
         let mut all_x = Vec::new();
         let mut all_y = Vec::new();
         for p in &self.tiles {
@@ -82,7 +84,7 @@ impl utils::Solution for Solution {
         }
     }
 
-    fn answer_part1(&self, _is_full: bool) -> Self::Result {
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
         let mut area = 0;
         for p1 in self.tiles.iter() {
             for p2 in self.tiles.iter() {
@@ -93,7 +95,7 @@ impl utils::Solution for Solution {
         Ok(area as ResultType)
     }
 
-    fn answer_part2(&self, _is_full: bool) -> Self::Result {
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
         let mut areas = Vec::new();
         for tile1 in self.compact_tiles.iter() {
             for tile2 in self.compact_tiles.iter() {
@@ -171,11 +173,6 @@ impl utils::Solution for Solution {
         }
         let area = best_area(&areas, &matrix);
 
-        // low: 39925070
-        // low: 107849020
-        // not: 1098764625
-        // [p]: 1574684850
-        // p1 : 4750176210
         // Implement for problem
         Ok(area as ResultType)
     }
@@ -1,3 +1,103 @@
-fn main() {
-    println!("Hello, world!");
+use std::{collections::BTreeSet, path::Path};
+
+use anyhow::{bail, Context, Result};
+use utils::{options::RunOptions, report::RunReport};
+
+type Runner = fn(&str, &[&str], &[&str], &RunOptions) -> Vec<RunReport>;
+
+macro_rules! days {
+    ($($day:ident),+ $(,)?) => {
+        &[$((stringify!($day), utils::run_day::<$day::Solution> as Runner)),+]
+    };
+}
+
+const DAYS: &[(&str, Runner)] =
+    days!(day1, day2, day3, day4, day5, day6, day7, day8, day9, day10, day11, day12,);
+
+const USAGE: &str = "usage: advent [options] <all | N | FROM..TO>...";
+
+fn main() -> Result<()> {
+    let _log = utils::log_init();
+
+    let (options, args) = RunOptions::parse(std::env::args().skip(1))?;
+    let selected = select_days(&args)?;
+    if options.has_inputs() && selected.len() > 1 {
+        bail!("--sample/--full can only be used with a single day");
+    }
+
+    let mut reports = Vec::new();
+    for (name, runner) in DAYS {
+        if selected.contains(&day_number(name)) {
+            let (samples, full) = utils::discover(Path::new("input"), name)?;
+            let samples = samples.iter().map(String::as_str).collect::<Vec<_>>();
+            let full = full.iter().map(String::as_str).collect::<Vec<_>>();
+            reports.extend(runner(name, &samples, &full, &options));
+        }
+    }
+    if !options.json {
+        print!("{}", utils::report::summary(&reports));
+    }
+    utils::check_reports(&reports)
+}
+
+fn day_number(name: &str) -> u32 {
+    name.trim_start_matches("day").parse().unwrap()
+}
+
+/// Resolve `all`, `N` and inclusive `FROM..TO` (or `FROM..=TO`) arguments to day numbers
+fn select_days(args: &[String]) -> Result<BTreeSet<u32>> {
+    if args.is_empty() {
+        bail!("{USAGE}\n{}", utils::options::USAGE);
+    }
+    let known = DAYS
+        .iter()
+        .map(|(name, _)| day_number(name))
+        .collect::<BTreeSet<_>>();
+    let mut selected = BTreeSet::new();
+    for arg in args {
+        if arg == "all" {
+            selected.extend(known.iter());
+            continue;
+        }
+        let parse = |v: &str| {
+            v.parse::<u32>()
+                .with_context(|| format!("invalid day '{arg}'\n{USAGE}"))
+        };
+        let (from, to) = match arg.split_once("..") {
+            Some((from, to)) => (parse(from)?, parse(to.trim_start_matches('='))?),
+            None => (parse(arg)?, parse(arg)?),
+        };
+        if from > to {
+            bail!("day range '{arg}' runs backwards\n{USAGE}");
+        }
+        for day in from..=to {
+            if !known.contains(&day) {
+                bail!("no solution registered for day {day}");
+            }
+            selected.insert(day);
+        }
+    }
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn select(args: &[&str]) -> Result<Vec<u32>> {
+        let args = args.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        select_days(&args).map(|days| days.into_iter().collect())
+    }
+
+    #[test]
+    fn selection() {
+        assert_eq!(vec![7], select(&["7"]).unwrap());
+        assert_eq!(vec![3, 4, 5], select(&["3..5"]).unwrap());
+        assert_eq!(vec![1, 3, 4], select(&["3..=4", "1"]).unwrap());
+        assert_eq!(DAYS.len(), select(&["all"]).unwrap().len());
+        assert!(select(&[]).is_err());
+        assert!(select(&["99"]).is_err());
+        assert!(select(&["x"]).is_err());
+        assert!(select(&["9..3"]).is_err());
+    }
 }
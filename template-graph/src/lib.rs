@@ -0,0 +1,82 @@
+use std::{
+    collections::HashMap,
+    io::{BufRead, BufReader},
+};
+#[allow(unused_imports)]
+use tracing::{debug, event_enabled, info, Level};
+use utils::{ParseError, RunContext};
+
+pub type ResultType = u64;
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    /// Adjacency list: each node's neighbours, in input order
+    edges: HashMap<String, Vec<String>>,
+}
+impl Solution {
+    fn add_edges(&mut self, node: &str, neighbours: Vec<String>) {
+        self.edges
+            .entry(node.to_string())
+            .or_default()
+            .extend(neighbours);
+    }
+}
+
+#[allow(unused_variables, unused_mut)]
+impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
+    type Error = ParseError;
+
+    fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
+        let mut solution = Self::default();
+        for (id, line) in reader.lines().map_while(Result::ok).enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (node, neighbours) = line.split_once(':').ok_or_else(|| {
+                ParseError::in_line(id, &line, &line, "expected 'node: neighbours...'")
+            })?;
+            let neighbours = neighbours.split_whitespace().map(str::to_string).collect();
+            solution.add_edges(node.trim(), neighbours);
+        }
+        Ok(solution)
+    }
+}
+impl utils::Solution for Solution {
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
+
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
+        // Implement for problem
+        Ok(0)
+    }
+
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
+        // Implement for problem
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use tracing_test::traced_test;
+    use utils::Solution;
+
+    #[test]
+    #[traced_test]
+    fn read() {
+        let input = "aaa: bbb ccc\nbbb: ccc\n";
+        let s: crate::Solution = utils::load_from_str(input).unwrap();
+        assert_eq!(
+            Some(&vec!["bbb".to_string(), "ccc".to_string()]),
+            s.edges.get("aaa")
+        );
+        assert_eq!(Some(&vec!["ccc".to_string()]), s.edges.get("bbb"));
+        assert_eq!(
+            0 as ResultType,
+            s.answer_part1(&RunContext::sample(&[])).unwrap()
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use std::io::BufReader;
+#[allow(unused_imports)]
+use tracing::{debug, event_enabled, info, Level};
+use utils::{
+    grid::{FixedGrid, ParseGrid},
+    ParseError, RunContext,
+};
+
+pub type ResultType = u64;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct Solution {
+    grid: FixedGrid<char>,
+}
+impl Solution {}
+
+#[allow(unused_variables, unused_mut)]
+impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
+    type Error = ParseError;
+
+    fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            grid: FixedGrid::from_reader(reader),
+        })
+    }
+}
+impl utils::Solution for Solution {
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
+
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
+        // Implement for problem
+        Ok(0)
+    }
+
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
+        // Implement for problem
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use tracing_test::traced_test;
+    use utils::Solution;
+
+    #[test]
+    #[traced_test]
+    fn read() {
+        let input = "#..\n.S#";
+        let s: crate::Solution = utils::load_from_str(input).unwrap();
+        assert_eq!((3, 2), (s.grid.max_x(), s.grid.max_y()));
+        assert_eq!(Some(&'S'), s.grid.get(1, 1));
+        assert_eq!(
+            0 as ResultType,
+            s.answer_part1(&RunContext::sample(&[])).unwrap()
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use std::io::{BufRead, BufReader};
+#[allow(unused_imports)]
+use tracing::{debug, event_enabled, info, Level};
+use utils::{parse_field, ParseError, RunContext};
+
+pub type ResultType = u64;
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    /// The numbers on each line, separated by whitespace and/or commas
+    numbers: Vec<Vec<i64>>,
+}
+impl Solution {}
+
+#[allow(unused_variables, unused_mut)]
+impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
+    type Error = ParseError;
+
+    fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
+        let mut solution = Self::default();
+        for (id, line) in reader.lines().map_while(Result::ok).enumerate() {
+            let numbers = line
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|field| !field.is_empty())
+                .map(|field| parse_field(id, &line, field))
+                .collect::<Result<Vec<_>, _>>()?;
+            solution.numbers.push(numbers);
+        }
+        Ok(solution)
+    }
+}
+impl utils::Solution for Solution {
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
+
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
+        // Implement for problem
+        Ok(0)
+    }
+
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
+        // Implement for problem
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use tracing_test::traced_test;
+    use utils::Solution;
+
+    #[test]
+    #[traced_test]
+    fn read() {
+        let input = "7 6 4 2 1\n1,-2, 3\n";
+        let s: crate::Solution = utils::load_from_str(input).unwrap();
+        assert_eq!(vec![vec![7, 6, 4, 2, 1], vec![1, -2, 3]], s.numbers);
+        assert!(utils::load_from_str::<crate::Solution>("1 x 3").is_err());
+        assert_eq!(
+            0 as ResultType,
+            s.answer_part1(&RunContext::sample(&[])).unwrap()
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use std::io::{BufRead, BufReader};
+#[allow(unused_imports)]
+use tracing::{debug, event_enabled, info, Level};
+use utils::{ParseError, RunContext};
+
+pub type ResultType = u64;
+
+#[derive(Debug, Default)]
+pub struct Solution {
+    /// Blocks of lines, separated in the input by blank lines
+    sections: Vec<Vec<String>>,
+}
+impl Solution {}
+
+#[allow(unused_variables, unused_mut)]
+impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
+    type Error = ParseError;
+
+    fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
+        let mut solution = Self::default();
+        let mut section = Vec::new();
+        for (id, line) in reader.lines().map_while(Result::ok).enumerate() {
+            if line.trim().is_empty() {
+                if !section.is_empty() {
+                    solution.sections.push(std::mem::take(&mut section));
+                }
+                continue;
+            }
+            // Implement for problem
+            section.push(line);
+        }
+        if !section.is_empty() {
+            solution.sections.push(section);
+        }
+        Ok(solution)
+    }
+}
+impl utils::Solution for Solution {
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
+
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
+        // Implement for problem
+        Ok(0)
+    }
+
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
+        // Implement for problem
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use tracing_test::traced_test;
+    use utils::Solution;
+
+    #[test]
+    #[traced_test]
+    fn read() {
+        let input = "3-5\n10-14\n\n\n1\n5\n";
+        let s: crate::Solution = utils::load_from_str(input).unwrap();
+        assert_eq!(vec![vec!["3-5", "10-14"], vec!["1", "5"]], s.sections);
+        assert_eq!(
+            0 as ResultType,
+            s.answer_part1(&RunContext::sample(&[])).unwrap()
+        );
+    }
+}
@@ -0,0 +1,8 @@
+use anyhow::Result;
+use template_sections::Solution;
+
+fn main() -> Result<()> {
+    let _log = utils::log_init();
+
+    utils::run_discovered::<Solution>()
+}
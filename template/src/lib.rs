@@ -1,6 +1,7 @@
 use std::io::{BufRead, BufReader};
 #[allow(unused_imports)]
 use tracing::{debug, event_enabled, info, Level};
+use utils::{ParseError, RunContext};
 
 pub type ResultType = u64;
 
@@ -10,7 +11,7 @@ impl Solution {}
 
 #[allow(unused_variables, unused_mut)]
 impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
-    type Error = std::io::Error;
+    type Error = ParseError;
 
     fn try_from(reader: BufReader<T>) -> Result<Self, Self::Error> {
         let mut solution = Self::default();
@@ -21,15 +22,16 @@ impl<T: std::io::Read> TryFrom<BufReader<T>> for Solution {
     }
 }
 impl utils::Solution for Solution {
-    type Result = anyhow::Result<ResultType>;
-    fn analyse(&mut self, _is_full: bool) {}
+    type Part1 = anyhow::Result<ResultType>;
+    type Part2 = anyhow::Result<ResultType>;
+    fn analyse(&mut self, _ctx: &RunContext) {}
 
-    fn answer_part1(&self, _is_full: bool) -> Self::Result {
+    fn answer_part1(&self, _ctx: &RunContext) -> Self::Part1 {
         // Implement for problem
         Ok(0)
     }
 
-    fn answer_part2(&self, _is_full: bool) -> Self::Result {
+    fn answer_part2(&self, _ctx: &RunContext) -> Self::Part2 {
         // Implement for problem
         Ok(0)
     }
@@ -38,7 +40,6 @@ impl utils::Solution for Solution {
 #[cfg(test)]
 mod test {
     use super::*;
-    use std::io::BufReader;
 
     use tracing_test::traced_test;
     use utils::Solution;
@@ -47,8 +48,10 @@ mod test {
     #[traced_test]
     fn read() {
         let input = "replace for problem";
-        let r = BufReader::new(input.as_bytes());
-        let s = crate::Solution::try_from(r).unwrap();
-        assert_eq!(0 as ResultType, s.answer_part1(false).unwrap());
+        let s: crate::Solution = utils::load_from_str(input).unwrap();
+        assert_eq!(
+            0 as ResultType,
+            s.answer_part1(&RunContext::sample(&[])).unwrap()
+        );
     }
 }
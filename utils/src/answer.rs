@@ -0,0 +1,56 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+
+/// What a part may return: a `Display` answer wrapped in a `Result` or `Option`
+pub trait Answer {
+    fn into_answer(self) -> Result<String>;
+}
+
+impl<T, E> Answer for std::result::Result<T, E>
+where
+    T: Display,
+    E: Into<anyhow::Error>,
+{
+    fn into_answer(self) -> Result<String> {
+        self.map(|answer| answer.to_string()).map_err(Into::into)
+    }
+}
+
+impl<T> Answer for Option<T>
+where
+    T: Display,
+{
+    fn into_answer(self) -> Result<String> {
+        self.map(|answer| answer.to_string())
+            .ok_or_else(|| anyhow!("no answer"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers() {
+        assert_eq!("7", Ok::<u64, anyhow::Error>(7).into_answer().unwrap());
+        assert_eq!(
+            "-170141183460469231731687303715884105728",
+            Ok::<_, anyhow::Error>(i128::MIN).into_answer().unwrap()
+        );
+        assert_eq!(
+            u128::MAX.to_string(),
+            Some(u128::MAX).into_answer().unwrap()
+        );
+        assert_eq!(
+            "1,2,3",
+            Ok::<_, std::fmt::Error>("1,2,3".to_string())
+                .into_answer()
+                .unwrap()
+        );
+        assert_eq!(
+            "no answer",
+            None::<String>.into_answer().unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use anyhow::{anyhow, Result};
+
+use crate::cancel::CancellationToken;
+
+/// What a solution knows about the run it is part of
+#[derive(Debug, Clone, Default)]
+pub struct RunContext {
+    is_full: bool,
+    params: BTreeMap<String, String>,
+    cancel: CancellationToken,
+}
+
+impl RunContext {
+    pub fn new(is_full: bool, params: BTreeMap<String, String>) -> Self {
+        Self {
+            is_full,
+            params,
+            cancel: CancellationToken::default(),
+        }
+    }
+
+    /// Context for a sample input, with the given parameters
+    pub fn sample(params: &[(&str, &str)]) -> Self {
+        Self::new(false, to_params(params))
+    }
+
+    /// Context for a full input, with the given parameters
+    pub fn full(params: &[(&str, &str)]) -> Self {
+        Self::new(true, to_params(params))
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.is_full
+    }
+
+    pub fn params(&self) -> &BTreeMap<String, String> {
+        &self.params
+    }
+
+    /// Typed value of parameter `name`, if it is set
+    pub fn param<T>(&self, name: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.params
+            .get(name)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|e| anyhow!("parameter '{name}': {e} ('{value}')"))
+            })
+            .transpose()
+    }
+
+    /// Typed value of parameter `name`, or `default` if it is not set
+    pub fn param_or<T>(&self, name: &str, default: T) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        Ok(self.param(name)?.unwrap_or(default))
+    }
+
+    /// Cancelled by the runner when the part runs over its time budget
+    pub fn cancellation(&self) -> &CancellationToken {
+        &self.cancel
+    }
+
+    pub(crate) fn with_cancellation(&self, cancel: CancellationToken) -> Self {
+        Self {
+            cancel,
+            ..self.clone()
+        }
+    }
+}
+
+fn to_params(params: &[(&str, &str)]) -> BTreeMap<String, String> {
+    params
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn params() {
+        let ctx = RunContext::sample(&[("limit", "10"), ("name", "x")]);
+        assert!(!ctx.is_full());
+        assert_eq!(Some(10u64), ctx.param("limit").unwrap());
+        assert_eq!(None, ctx.param::<u64>("missing").unwrap());
+        assert_eq!(1000u64, ctx.param_or("missing", 1000).unwrap());
+        let e = ctx.param::<u64>("name").unwrap_err();
+        assert_eq!(
+            "parameter 'name': invalid digit found in string ('x')",
+            e.to_string()
+        );
+        assert!(RunContext::full(&[]).is_full());
+    }
+}
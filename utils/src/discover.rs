@@ -0,0 +1,77 @@
+use std::{cmp::Ordering, path::Path};
+
+use anyhow::{Context, Result};
+
+/// Suffixes of every `{basename}.sample*` and `{basename}.full*` file in `dir`, in natural order
+/// (`sample`, `sample2`, `sample10`).
+///
+/// Inputs named `full*` are full inputs; sidecars such as `sample.expected` are skipped.
+pub fn discover(dir: &Path, basename: &str) -> Result<(Vec<String>, Vec<String>)> {
+    let prefix = format!("{basename}.");
+    let mut samples = Vec::new();
+    let mut full = Vec::new();
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok((samples, full)),
+        Err(e) => return Err(e).with_context(|| format!("reading {dir:?}")),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name();
+        let Some(suffix) = name.to_str().and_then(|name| name.strip_prefix(&prefix)) else {
+            continue;
+        };
+        if suffix.contains('.') || !entry.file_type()?.is_file() {
+            continue;
+        }
+        if suffix.starts_with("sample") {
+            samples.push(suffix.to_string());
+        } else if suffix.starts_with("full") {
+            full.push(suffix.to_string());
+        }
+    }
+    samples.sort_by(|a, b| natural_order(a, b));
+    full.sort_by(|a, b| natural_order(a, b));
+    Ok((samples, full))
+}
+
+/// Order by leading text, then by trailing number (none first)
+fn natural_order(a: &str, b: &str) -> Ordering {
+    let split = |s: &str| {
+        let text = s.trim_end_matches(|c: char| c.is_ascii_digit());
+        let number = s[text.len()..].parse::<u64>().ok();
+        (text.to_string(), number)
+    };
+    split(a).cmp(&split(b)).then_with(|| a.cmp(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discovers_inputs() {
+        let dir = std::env::temp_dir().join(format!("discover-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "day1.sample10",
+            "day1.sample",
+            "day1.sample2",
+            "day1.sample.expected",
+            "day1.full",
+            "day1.ledger",
+            "day11.sample",
+        ] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+        let (samples, full) = discover(&dir, "day1").unwrap();
+        assert_eq!(vec!["sample", "sample2", "sample10"], samples);
+        assert_eq!(vec!["full"], full);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            (Vec::new(), Vec::new()),
+            discover(&dir.join("missing"), "day1").unwrap()
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use yansi::Paint;
+
+use crate::{report::Part, sidecar};
+
+/// Known answers for an input, stored in `<input>.expected` as `part1 = ...` / `part2 = ...`
+#[derive(Debug, Default, Clone)]
+pub struct Expected {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Expected {
+    pub fn load(input: &str) -> Result<Self> {
+        let values = sidecar::load(input, "expected")?;
+        let answer = |part: Part| {
+            values
+                .get(&part.to_string())
+                .filter(|v| !v.is_empty())
+                .cloned()
+        };
+        Ok(Self {
+            part1: answer(Part::One),
+            part2: answer(Part::Two),
+        })
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// Contradicts the day's guess ledger
+    Rejected {
+        reason: String,
+    },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Fail { .. } | Verdict::Rejected { .. })
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail { .. } => "FAIL",
+            Verdict::Rejected { .. } => "REJECTED",
+            Verdict::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "{}", Paint::green("PASS")),
+            Verdict::Fail { expected } => {
+                write!(f, "{} (expected {})", Paint::red("FAIL"), expected)
+            }
+            Verdict::Rejected { reason } => write!(f, "{} ({})", Paint::red("REJECTED"), reason),
+            Verdict::Unknown => write!(f, "{}", Paint::yellow("UNKNOWN")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let expected = Expected {
+            part1: Some("3".to_string()),
+            part2: None,
+        };
+        assert_eq!(Verdict::Pass, expected.check(Part::One, "3"));
+        assert_eq!(
+            Verdict::Fail {
+                expected: "3".to_string()
+            },
+            expected.check(Part::One, "4")
+        );
+        assert_eq!(Verdict::Unknown, expected.check(Part::Two, "4"));
+    }
+}
@@ -1,9 +1,15 @@
 mod fixedgrid;
 mod matrix;
+mod parse;
 mod picture;
 mod sparsegrid;
+mod traits;
+mod transform;
 
 pub use fixedgrid::FixedGrid;
 pub use matrix::Matrix;
+pub use parse::{Markers, ParseGrid};
 pub use picture::Picture;
 pub use sparsegrid::SparseGrid;
+pub use traits::{Bounds, Edge, Grid, WithSize};
+pub use transform::Transform;
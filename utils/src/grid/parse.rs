@@ -0,0 +1,114 @@
+use std::{
+    collections::BTreeMap,
+    io::{BufRead, BufReader, Read},
+};
+
+use crate::point::Point;
+
+use super::{Grid, WithSize};
+
+/// Positions of marker chars (e.g. an `S` start) found while parsing a grid, in reading order
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Markers(BTreeMap<char, Vec<Point<isize>>>);
+
+impl Markers {
+    pub fn get(&self, marker: char) -> &[Point<isize>] {
+        self.0.get(&marker).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn first(&self, marker: char) -> Option<Point<isize>> {
+        self.get(marker).first().copied()
+    }
+}
+
+/// Build a grid from puzzle text: char `x` of line `y` becomes cell (x, y)
+pub trait ParseGrid: Grid + WithSize + Sized {
+    /// Parse `lines`, converting each char with `mapping` and recording where any of `markers`
+    /// appear. Trailing whitespace, including the `\r` of CRLF line endings, is not part of the
+    /// grid
+    fn parse_lines<I, S, F>(lines: I, markers: &[char], mut mapping: F) -> (Self, Markers)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        F: FnMut(char) -> Self::Item,
+    {
+        let lines = lines.into_iter().collect::<Vec<_>>();
+        let lines = lines
+            .iter()
+            .map(|line| line.as_ref().trim_end())
+            .collect::<Vec<_>>();
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut grid = Self::with_size(width, lines.len());
+        let mut found = Markers::default();
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let point = Point::new(x as isize, y as isize);
+                if markers.contains(&c) {
+                    found.0.entry(c).or_default().push(point);
+                }
+                grid.set(point, mapping(c));
+            }
+        }
+        (grid, found)
+    }
+
+    fn from_lines<I, S>(lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        Self::Item: From<char>,
+    {
+        Self::parse_lines(lines, &[], Self::Item::from).0
+    }
+
+    fn from_text(text: &str) -> Self
+    where
+        Self::Item: From<char>,
+    {
+        Self::from_lines(text.lines())
+    }
+
+    /// Every line of `reader`, stopping at the first that cannot be read
+    fn from_reader<R: Read>(reader: BufReader<R>) -> Self
+    where
+        Self::Item: From<char>,
+    {
+        Self::from_lines(reader.lines().map_while(Result::ok))
+    }
+}
+
+impl<G: Grid + WithSize> ParseGrid for G {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{FixedGrid, Matrix, SparseGrid};
+
+    const TEXT: &str = ".S.\n^.^\n";
+
+    #[test]
+    fn parse() {
+        let grid = FixedGrid::<char>::from_text(TEXT);
+        assert_eq!((3, 2), (grid.max_x(), grid.max_y()));
+        assert_eq!(TEXT, grid.render());
+        let grid = Matrix::<char>::from_reader(BufReader::new(TEXT.as_bytes()));
+        assert_eq!(TEXT, grid.render());
+        let grid = Matrix::<char>::from_text(".S. \r\n^.^\r\n");
+        assert_eq!(TEXT, grid.render());
+    }
+
+    #[test]
+    fn mapping_and_markers() {
+        let (grid, markers) =
+            SparseGrid::<bool, isize>::parse_lines(TEXT.lines(), &['S', '^'], |c| c != '.');
+        assert_eq!(Some(Point::new(1, 0)), markers.first('S'));
+        assert_eq!(&[Point::new(0, 1), Point::new(2, 1)], markers.get('^'));
+        assert!(markers.get('E').is_empty());
+        assert_eq!(Some(&true), Grid::get(&grid, Point::new(2, 1)));
+        assert_eq!(Some(&false), Grid::get(&grid, Point::new(1, 1)));
+    }
+}
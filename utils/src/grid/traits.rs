@@ -0,0 +1,430 @@
+use std::fmt::Display;
+
+use crate::point::{Direction, Point};
+
+use super::{FixedGrid, Matrix, SparseGrid};
+
+/// Inclusive rectangle of grid coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Point<isize>,
+    pub max: Point<isize>,
+}
+
+impl Bounds {
+    pub fn new(min: Point<isize>, max: Point<isize>) -> Self {
+        Self { min, max }
+    }
+
+    /// The smallest bounds containing every one of `points`, `None` if there are none
+    pub fn enclosing(points: impl IntoIterator<Item = Point<isize>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(
+            points.fold(Self::new(first, first), |Self { min, max }, p| {
+                Self::new(
+                    Point::new(min.x().min(p.x()), min.y().min(p.y())),
+                    Point::new(max.x().max(p.x()), max.y().max(p.y())),
+                )
+            }),
+        )
+    }
+
+    pub fn contains(&self, point: Point<isize>) -> bool {
+        (self.min.x()..=self.max.x()).contains(&point.x())
+            && (self.min.y()..=self.max.y()).contains(&point.y())
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x() - self.min.x() + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y() - self.min.y() + 1) as usize
+    }
+
+    /// Every point, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point<isize>> {
+        let Self { min, max } = *self;
+        (min.y()..=max.y()).flat_map(move |y| (min.x()..=max.x()).map(move |x| Point::new(x, y)))
+    }
+
+    /// The nearest point inside the bounds
+    pub fn clamp(&self, point: Point<isize>) -> Point<isize> {
+        Point::new(
+            point.x().clamp(self.min.x(), self.max.x()),
+            point.y().clamp(self.min.y(), self.max.y()),
+        )
+    }
+
+    /// The point inside the bounds reached by wrapping around each edge, as on a torus
+    pub fn wrap(&self, point: Point<isize>) -> Point<isize> {
+        Point::new(
+            self.min.x() + (point.x() - self.min.x()).rem_euclid(self.width() as isize),
+            self.min.y() + (point.y() - self.min.y()).rem_euclid(self.height() as isize),
+        )
+    }
+
+    /// The 8 surrounding points, with those off the edge handled by `edge`
+    pub fn neighbours(
+        self,
+        point: Point<isize>,
+        edge: Edge<'_>,
+    ) -> impl Iterator<Item = Point<isize>> + '_ {
+        Direction::iter().filter_map(move |d| edge.apply(&self, point + d))
+    }
+
+    /// The points north, east, south and west, with those off the edge handled by `edge`
+    pub fn cardinal_neighbours(
+        self,
+        point: Point<isize>,
+        edge: Edge<'_>,
+    ) -> impl Iterator<Item = Point<isize>> + '_ {
+        CARDINALS
+            .into_iter()
+            .filter_map(move |d| edge.apply(&self, point + d))
+    }
+}
+
+const CARDINALS: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+type EdgeMapping = dyn Fn(Point<isize>, &Bounds) -> Option<Point<isize>>;
+
+/// What becomes of a neighbour that falls outside a grid's [`Bounds`]
+#[derive(Clone, Copy)]
+pub enum Edge<'a> {
+    /// Pull it back to the nearest point on the edge, which may be the cell itself
+    Clamp,
+    /// Continue from the opposite edge, as on a torus
+    Wrap,
+    /// Map it with a function, `None` dropping it
+    Custom(&'a EdgeMapping),
+}
+
+impl Edge<'_> {
+    /// `point` itself if inside `bounds`, otherwise where this edge mode sends it
+    pub fn apply(&self, bounds: &Bounds, point: Point<isize>) -> Option<Point<isize>> {
+        if bounds.contains(point) {
+            return Some(point);
+        }
+        match self {
+            Self::Clamp => Some(bounds.clamp(point)),
+            Self::Wrap => Some(bounds.wrap(point)),
+            Self::Custom(mapping) => mapping(point, bounds),
+        }
+    }
+}
+
+/// Grids that can be created empty, for building one from another or from text
+pub trait WithSize {
+    /// An empty grid with room for `width` x `height` cells from the origin
+    fn with_size(width: usize, height: usize) -> Self;
+}
+
+impl<T: Default> WithSize for FixedGrid<T> {
+    fn with_size(width: usize, height: usize) -> Self {
+        Self::new(width, height)
+    }
+}
+
+impl<T> WithSize for Matrix<T>
+where
+    T: Default + Display + Clone,
+{
+    fn with_size(_width: usize, _height: usize) -> Self {
+        Self::new()
+    }
+}
+
+impl<T> WithSize for SparseGrid<T, isize>
+where
+    T: Default + Display + Clone,
+{
+    fn with_size(_width: usize, _height: usize) -> Self {
+        Self::new()
+    }
+}
+
+/// Common interface of the grid types, so that algorithms can be written once for any of them.
+///
+/// Sparse grids only hold the cells that were set; [`Grid::get`] is `None` elsewhere.
+pub trait Grid {
+    type Item;
+
+    fn get(&self, point: Point<isize>) -> Option<&Self::Item>;
+
+    /// Set a cell; fixed-size grids ignore points outside their bounds
+    fn set(&mut self, point: Point<isize>, value: Self::Item);
+
+    /// Smallest rectangle containing every cell, `None` if there are none
+    fn bounds(&self) -> Option<Bounds>;
+
+    /// Every cell holding a value, in no particular order
+    fn cells(&self) -> impl Iterator<Item = (Point<isize>, &Self::Item)>;
+
+    fn contains(&self, point: Point<isize>) -> bool {
+        self.bounds().is_some_and(|bounds| bounds.contains(point))
+    }
+
+    /// The (up to 8) surrounding cells holding a value
+    fn neighbours(&self, point: Point<isize>) -> impl Iterator<Item = (Point<isize>, &Self::Item)> {
+        Direction::iter().filter_map(move |d| {
+            let neighbour = point + d;
+            self.get(neighbour).map(|v| (neighbour, v))
+        })
+    }
+
+    /// The (up to 4) cells north, east, south and west holding a value
+    fn cardinal_neighbours(
+        &self,
+        point: Point<isize>,
+    ) -> impl Iterator<Item = (Point<isize>, &Self::Item)> {
+        CARDINALS.into_iter().filter_map(move |d| {
+            let neighbour = point + d;
+            self.get(neighbour).map(|v| (neighbour, v))
+        })
+    }
+
+    /// As [`Grid::neighbours`], with neighbours off the edge of the bounds handled by `edge`
+    fn neighbours_with(
+        &self,
+        point: Point<isize>,
+        edge: Edge<'_>,
+    ) -> impl Iterator<Item = (Point<isize>, &Self::Item)> {
+        self.bounds()
+            .into_iter()
+            .flat_map(move |bounds| bounds.neighbours(point, edge))
+            .filter_map(move |neighbour| self.get(neighbour).map(|v| (neighbour, v)))
+    }
+
+    /// As [`Grid::cardinal_neighbours`], with neighbours off the edge of the bounds handled by
+    /// `edge`
+    fn cardinal_neighbours_with(
+        &self,
+        point: Point<isize>,
+        edge: Edge<'_>,
+    ) -> impl Iterator<Item = (Point<isize>, &Self::Item)> {
+        self.bounds()
+            .into_iter()
+            .flat_map(move |bounds| bounds.cardinal_neighbours(point, edge))
+            .filter_map(move |neighbour| self.get(neighbour).map(|v| (neighbour, v)))
+    }
+
+    /// One line per row of the bounds, mapping each cell (`None` where unset) to text
+    fn render_with<F>(&self, mapping: F) -> String
+    where
+        F: Fn(Option<&Self::Item>) -> String,
+    {
+        let mut text = String::new();
+        if let Some(bounds) = self.bounds() {
+            for y in bounds.min.y()..=bounds.max.y() {
+                for x in bounds.min.x()..=bounds.max.x() {
+                    text.push_str(&mapping(self.get(Point::new(x, y))));
+                }
+                text.push('\n');
+            }
+        }
+        text
+    }
+
+    /// As [`Grid::render_with`], showing unset cells as `.`
+    fn render(&self) -> String
+    where
+        Self::Item: Display,
+    {
+        self.render_with(|v| v.map_or_else(|| ".".to_string(), |v| v.to_string()))
+    }
+}
+
+impl<T> Grid for FixedGrid<T> {
+    type Item = T;
+
+    fn get(&self, point: Point<isize>) -> Option<&T> {
+        FixedGrid::get(self, point.x(), point.y())
+    }
+
+    fn set(&mut self, point: Point<isize>, value: T) {
+        FixedGrid::set(self, point.x(), point.y(), value)
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        (self.max_x() > 0 && self.max_y() > 0).then(|| {
+            Bounds::new(
+                Point::new(0, 0),
+                Point::new(self.max_x() as isize - 1, self.max_y() as isize - 1),
+            )
+        })
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (Point<isize>, &T)> {
+        self.bounds()
+            .into_iter()
+            .flat_map(|bounds| bounds.points())
+            .filter_map(|point| Grid::get(self, point).map(|v| (point, v)))
+    }
+}
+
+impl<T> Grid for Matrix<T>
+where
+    T: Default + Display + Clone,
+{
+    type Item = T;
+
+    fn get(&self, point: Point<isize>) -> Option<&T> {
+        Matrix::get(self, point.x(), point.y())
+    }
+
+    fn set(&mut self, point: Point<isize>, value: T) {
+        Matrix::set(self, point.x(), point.y(), value)
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        // Matrix's own minima start at the origin, whether or not it holds a cell there
+        Bounds::enclosing(self.sparse_iter().map(|((x, y), _)| Point::new(*x, *y)))
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (Point<isize>, &T)> {
+        self.sparse_iter()
+            .map(|((x, y), v)| (Point::new(*x, *y), v))
+    }
+}
+
+impl<T> Grid for SparseGrid<T, isize>
+where
+    T: Default + Display + Clone,
+{
+    type Item = T;
+
+    fn get(&self, point: Point<isize>) -> Option<&T> {
+        SparseGrid::get(self, &point)
+    }
+
+    fn set(&mut self, point: Point<isize>, value: T) {
+        SparseGrid::set(self, &point, value)
+    }
+
+    fn bounds(&self) -> Option<Bounds> {
+        (!self.is_empty()).then(|| {
+            Bounds::new(
+                Point::new(*self.min_x(), *self.min_y()),
+                Point::new(*self.max_x(), *self.max_y()),
+            )
+        })
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (Point<isize>, &T)> {
+        self.iter().map(|(point, v)| (*point, v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draw a plus sign centred on (1, 1), then check it through the trait alone
+    fn check_plus<G: Grid<Item = char>>(mut grid: G) {
+        for (x, y) in [(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)] {
+            grid.set(Point::new(x, y), '#');
+        }
+        let centre = Point::new(1, 1);
+        assert_eq!(Some(&'#'), grid.get(centre));
+        assert_eq!(
+            4,
+            grid.neighbours(centre).filter(|(_, c)| **c == '#').count()
+        );
+        assert_eq!(4, grid.cardinal_neighbours(centre).count());
+        assert_eq!(
+            Some(Bounds::new(Point::new(0, 0), Point::new(2, 2))),
+            grid.bounds()
+        );
+        assert!(grid.contains(Point::new(2, 2)));
+        assert!(!grid.contains(Point::new(3, 2)));
+        assert_eq!(5, grid.cells().filter(|(_, c)| **c == '#').count());
+    }
+
+    #[test]
+    fn grids() {
+        let mut fixed = FixedGrid::new(3, 3);
+        for point in Bounds::new(Point::new(0, 0), Point::new(2, 2)).points() {
+            Grid::set(&mut fixed, point, '.');
+        }
+        check_plus(fixed);
+        check_plus(Matrix::new());
+        check_plus(SparseGrid::<char, isize>::new());
+    }
+
+    #[test]
+    fn matrix_away_from_origin() {
+        let mut matrix = Matrix::new();
+        Grid::set(&mut matrix, Point::new(3, 0), '#');
+        Grid::set(&mut matrix, Point::new(4, 2), '#');
+        assert_eq!(
+            Some(Bounds::new(Point::new(3, 0), Point::new(4, 2))),
+            matrix.bounds()
+        );
+        assert!(!matrix.contains(Point::new(0, 0)));
+    }
+
+    #[test]
+    fn edges() {
+        let mut grid = FixedGrid::new(3, 2);
+        for (i, point) in Bounds::new(Point::new(0, 0), Point::new(2, 1))
+            .points()
+            .enumerate()
+        {
+            Grid::set(&mut grid, point, i);
+        }
+        let values = |edge| {
+            let mut values = grid
+                .neighbours_with(Point::new(0, 0), edge)
+                .map(|(_, v)| *v)
+                .collect::<Vec<_>>();
+            values.sort();
+            values
+        };
+        assert_eq!(vec![0, 0, 0, 1, 1, 3, 3, 4], values(Edge::Clamp));
+        assert_eq!(vec![1, 2, 3, 3, 4, 4, 5, 5], values(Edge::Wrap));
+        // A cylinder: wrap east-west, nothing beyond the top and bottom
+        let cylinder = |point: Point<isize>, bounds: &Bounds| {
+            let wrapped = bounds.wrap(point);
+            (wrapped.y() == point.y()).then_some(wrapped)
+        };
+        assert_eq!(vec![1, 2, 3, 4, 5], values(Edge::Custom(&cylinder)));
+        assert_eq!(
+            vec![
+                (Point::new(1, 0), &1),
+                (Point::new(0, 1), &3),
+                (Point::new(2, 0), &2)
+            ],
+            grid.cardinal_neighbours_with(Point::new(0, 0), Edge::Custom(&cylinder))
+                .collect::<Vec<_>>()
+        );
+
+        let mut sparse = SparseGrid::<char, isize>::new();
+        Grid::set(&mut sparse, Point::new(-1, -1), '#');
+        Grid::set(&mut sparse, Point::new(1, 1), 'S');
+        let bounds = sparse.bounds().unwrap();
+        assert_eq!(Point::new(-1, 0), bounds.wrap(Point::new(2, 3)));
+        assert_eq!(Point::new(1, -1), bounds.clamp(Point::new(5, -4)));
+        assert_eq!(
+            vec![(Point::new(-1, -1), &'#')],
+            sparse
+                .neighbours_with(Point::new(1, 1), Edge::Wrap)
+                .filter(|(_, c)| **c == '#')
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn render() {
+        let mut grid = SparseGrid::<char, isize>::new();
+        Grid::set(&mut grid, Point::new(-1, 0), '#');
+        Grid::set(&mut grid, Point::new(1, 1), 'S');
+        assert_eq!("#..\n..S\n", grid.render());
+        assert_eq!(
+            "X__\n__X\n",
+            grid.render_with(|v| if v.is_some() { "X" } else { "_" }.to_string())
+        );
+        assert_eq!("", Matrix::<char>::new().render());
+    }
+}
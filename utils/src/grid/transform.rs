@@ -0,0 +1,185 @@
+use crate::point::Point;
+
+use super::{Bounds, Grid, WithSize};
+
+/// Rotations, reflections and cropping, e.g. to try every orientation of a polyomino.
+///
+/// Results are re-based so that their bounds start at the origin; sparse grids stay sparse.
+pub trait Transform: Grid + WithSize + Sized
+where
+    Self::Item: Clone,
+{
+    /// A quarter turn clockwise
+    fn rotate90(&self) -> Self {
+        remap(self, true, |x, y, _, height| (height - 1 - y, x))
+    }
+
+    fn rotate180(&self) -> Self {
+        remap(self, false, |x, y, width, height| {
+            (width - 1 - x, height - 1 - y)
+        })
+    }
+
+    /// A quarter turn anticlockwise
+    fn rotate270(&self) -> Self {
+        remap(self, true, |x, y, width, _| (y, width - 1 - x))
+    }
+
+    /// Mirror left to right
+    fn flip_horizontal(&self) -> Self {
+        remap(self, false, |x, y, width, _| (width - 1 - x, y))
+    }
+
+    /// Mirror top to bottom
+    fn flip_vertical(&self) -> Self {
+        remap(self, false, |x, y, _, height| (x, height - 1 - y))
+    }
+
+    /// Mirror along the leading diagonal, swapping x and y
+    fn transpose(&self) -> Self {
+        remap(self, true, |x, y, _, _| (y, x))
+    }
+
+    /// The smallest rectangle holding every cell that satisfies `content`
+    fn crop<F>(&self, content: F) -> Self
+    where
+        F: Fn(&Self::Item) -> bool,
+    {
+        let Some(bounds) = Bounds::enclosing(
+            self.cells()
+                .filter(|(_, v)| content(v))
+                .map(|(point, _)| point),
+        ) else {
+            return Self::with_size(0, 0);
+        };
+        let mut cropped = Self::with_size(bounds.width(), bounds.height());
+        for point in bounds.points() {
+            if let Some(v) = self.get(point) {
+                cropped.set(point - bounds.min, v.clone());
+            }
+        }
+        cropped
+    }
+
+    /// Every distinct orientation under rotation and reflection: the grid itself first, then up
+    /// to 7 more
+    fn variants(&self) -> Vec<Self>
+    where
+        Self::Item: PartialEq,
+    {
+        let mut variants: Vec<Self> = Vec::with_capacity(8);
+        let original = remap(self, false, |x, y, _, _| (x, y));
+        for grid in [original, self.flip_horizontal()] {
+            let quarter = grid.rotate90();
+            let half = quarter.rotate90();
+            let three_quarters = half.rotate90();
+            for candidate in [grid, quarter, half, three_quarters] {
+                if !variants.iter().any(|v| same_cells(v, &candidate)) {
+                    variants.push(candidate);
+                }
+            }
+        }
+        variants
+    }
+}
+
+impl<G> Transform for G
+where
+    G: Grid + WithSize,
+    G::Item: Clone,
+{
+}
+
+/// Copy every cell of `grid` to `position(x, y, width, height)`, with coordinates relative to
+/// its bounds; `swap` gives the result transposed dimensions
+fn remap<G, F>(grid: &G, swap: bool, position: F) -> G
+where
+    G: Grid + WithSize,
+    G::Item: Clone,
+    F: Fn(isize, isize, isize, isize) -> (isize, isize),
+{
+    let Some(bounds) = grid.bounds() else {
+        return G::with_size(0, 0);
+    };
+    let (width, height) = (bounds.width(), bounds.height());
+    let mut result = if swap {
+        G::with_size(height, width)
+    } else {
+        G::with_size(width, height)
+    };
+    for (point, v) in grid.cells() {
+        let relative = point - bounds.min;
+        let (x, y) = position(relative.x(), relative.y(), width as isize, height as isize);
+        result.set(Point::new(x, y), v.clone());
+    }
+    result
+}
+
+fn same_cells<G>(a: &G, b: &G) -> bool
+where
+    G: Grid,
+    G::Item: PartialEq,
+{
+    let bounds = a.bounds();
+    bounds == b.bounds()
+        && bounds.is_none_or(|bounds| bounds.points().all(|point| a.get(point) == b.get(point)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{FixedGrid, Matrix, ParseGrid, SparseGrid};
+
+    const L: &str = "#.\n#.\n##\n";
+
+    #[test]
+    fn rotations_and_flips() {
+        let grid = FixedGrid::<char>::from_text(L);
+        assert_eq!("###\n#..\n", grid.rotate90().render());
+        assert_eq!("##\n.#\n.#\n", grid.rotate180().render());
+        assert_eq!("..#\n###\n", grid.rotate270().render());
+        assert_eq!(".#\n.#\n##\n", grid.flip_horizontal().render());
+        assert_eq!("##\n#.\n#.\n", grid.flip_vertical().render());
+        assert_eq!("###\n..#\n", grid.transpose().render());
+        assert_eq!(L, grid.rotate90().rotate270().render());
+        assert_eq!(
+            grid.rotate90().render(),
+            Matrix::<char>::from_text(L).rotate90().render()
+        );
+    }
+
+    #[test]
+    fn crop() {
+        let grid = Matrix::<char>::from_text("....\n.#..\n.##.\n....\n");
+        assert_eq!("#.\n##\n", grid.crop(|c| *c == '#').render());
+        assert_eq!("", grid.crop(|c| *c == 'S').render());
+
+        let mut sparse = SparseGrid::<char, isize>::new();
+        Grid::set(&mut sparse, Point::new(-3, 5), '#');
+        Grid::set(&mut sparse, Point::new(-2, 6), '#');
+        let cropped = sparse.crop(|_| true);
+        assert_eq!(Some(&'#'), Grid::get(&cropped, Point::new(0, 0)));
+        assert_eq!(None, Grid::get(&cropped, Point::new(1, 0)));
+        assert_eq!("#.\n.#\n", cropped.render());
+    }
+
+    #[test]
+    fn matrix_away_from_origin() {
+        let mut matrix = Matrix::new();
+        Grid::set(&mut matrix, Point::new(3, 0), '#');
+        let rotated = matrix.rotate90();
+        assert_eq!(Some(&'#'), Grid::get(&rotated, Point::new(0, 0)));
+        assert_eq!("#\n", rotated.render());
+    }
+
+    #[test]
+    fn variants() {
+        let count = |text| FixedGrid::<char>::from_text(text).variants().len();
+        assert_eq!(8, count(L));
+        assert_eq!(1, count("##\n##\n"));
+        assert_eq!(2, count("###\n"));
+        assert_eq!(4, count("##.\n.##\n"));
+        let variants = FixedGrid::<char>::from_text(L).variants();
+        assert_eq!(L, variants[0].render());
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use anyhow::{anyhow, Result};
+
+thread_local! {
+    static ISOLATED: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+/// Run `f`, turning a panic into an error carrying the panic message and location
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T> {
+    install_hook();
+    let was_isolated = ISOLATED.with(|isolated| isolated.replace(true));
+    let r = panic::catch_unwind(AssertUnwindSafe(f));
+    ISOLATED.with(|isolated| isolated.set(was_isolated));
+    r.map_err(|payload| {
+        let caught = CAUGHT.with(|caught| caught.borrow_mut().take());
+        anyhow!(caught.unwrap_or_else(|| format!("panicked: {}", message(payload.as_ref()))))
+    })
+}
+
+/// Record isolated panics rather than printing them, leaving all others to the previous hook
+fn install_hook() {
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if ISOLATED.with(Cell::get) {
+                let location = info
+                    .location()
+                    .map_or("unknown location".to_string(), |l| l.to_string());
+                let caught = format!("panicked at {}: {}", location, message(info.payload()));
+                CAUGHT.with(|c| *c.borrow_mut() = Some(caught));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+fn message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s
+    } else {
+        "non-string panic payload"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_panic() {
+        assert_eq!(3, catch_panic(|| 1 + 2).unwrap());
+    }
+
+    #[test]
+    fn panic_message_and_location() {
+        let e = catch_panic(|| -> u64 { panic!("broken {}", 42) }).unwrap_err();
+        let e = e.to_string();
+        assert!(e.starts_with("panicked at utils/src/isolation.rs:"), "{e}");
+        assert!(e.ends_with(": broken 42"), "{e}");
+    }
+
+    #[test]
+    fn nested() {
+        let r = catch_panic(|| {
+            let inner = catch_panic(|| -> u64 { panic!("inner") });
+            assert!(inner.is_err());
+            7
+        });
+        assert_eq!(7, r.unwrap());
+    }
+}
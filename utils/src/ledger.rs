@@ -0,0 +1,239 @@
+use std::{fmt::Display, str::FromStr};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{expected::Verdict, report::Part};
+
+/// How a submitted answer was received
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Submitted, but the response was not noted
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Correct => write!(f, "correct"),
+            Status::Wrong => write!(f, "wrong"),
+            Status::TooHigh => write!(f, "too-high"),
+            Status::TooLow => write!(f, "too-low"),
+            Status::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for Status {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Status::Correct),
+            "wrong" => Ok(Status::Wrong),
+            "too-high" => Ok(Status::TooHigh),
+            "too-low" => Ok(Status::TooLow),
+            "unknown" => Ok(Status::Unknown),
+            _ => {
+                bail!("unknown status '{s}', expected correct, wrong, too-high, too-low or unknown")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub part: Part,
+    pub status: Status,
+    pub answer: String,
+}
+
+impl Display for Guess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.part, self.status, self.answer)
+    }
+}
+
+/// Answers already submitted for a day's full input, stored in `input/<day>.ledger` as
+/// `<part> <status> <answer>` lines, e.g. `part2 too-low 39925070`
+#[derive(Debug, Clone, Default)]
+pub struct Ledger {
+    guesses: Vec<Guess>,
+}
+
+impl Ledger {
+    pub fn path(day: &str) -> String {
+        format!("input/{day}.ledger")
+    }
+
+    /// Read the ledger at `path`, which is empty if there is no such file
+    pub fn load(path: &str) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents).with_context(|| format!("reading '{path}'")),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("reading '{path}'")),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut guesses = Vec::new();
+        for (id, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [part, status, answer] = fields[..] else {
+                bail!(
+                    "line {}: expected '<part> <status> <answer>', got '{}'",
+                    id + 1,
+                    line
+                );
+            };
+            guesses.push(Guess {
+                part: part.parse().with_context(|| format!("line {}", id + 1))?,
+                status: status.parse().with_context(|| format!("line {}", id + 1))?,
+                answer: answer.to_string(),
+            });
+        }
+        Ok(Self { guesses })
+    }
+
+    /// Append `guess` to the ledger at `path`, creating it if need be
+    pub fn record(path: &str, guess: &Guess) -> Result<()> {
+        use std::io::Write;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("opening '{path}'"))?;
+        writeln!(file, "{guess}").with_context(|| format!("writing '{path}'"))
+    }
+
+    pub fn guesses(&self) -> &[Guess] {
+        &self.guesses
+    }
+
+    /// The answer accepted for `part`, if any
+    pub fn correct(&self, part: Part) -> Option<&str> {
+        self.guesses
+            .iter()
+            .find(|g| g.part == part && g.status == Status::Correct)
+            .map(|g| g.answer.as_str())
+    }
+
+    /// Judge `answer` against the guesses already made for `part`
+    pub fn check(&self, part: Part, answer: &str) -> Verdict {
+        if let Some(correct) = self.correct(part) {
+            return if correct == answer {
+                Verdict::Pass
+            } else {
+                Verdict::Fail {
+                    expected: correct.to_string(),
+                }
+            };
+        }
+        let guesses = || self.guesses.iter().filter(move |g| g.part == part);
+        if let Some(guess) = guesses().find(|g| g.answer == answer && g.status != Status::Unknown) {
+            return Verdict::Rejected {
+                reason: format!("already guessed, {}", guess.status),
+            };
+        }
+        let Ok(value) = answer.parse::<i128>() else {
+            return Verdict::Unknown;
+        };
+        let bound = |status| {
+            guesses()
+                .filter(move |g| g.status == status)
+                .filter_map(|g| g.answer.parse::<i128>().ok())
+        };
+        if let Some(low) = bound(Status::TooLow).max().filter(|low| value <= *low) {
+            return Verdict::Rejected {
+                reason: format!("at or below too-low guess {low}"),
+            };
+        }
+        if let Some(high) = bound(Status::TooHigh).min().filter(|high| value >= *high) {
+            return Verdict::Rejected {
+                reason: format!("at or above too-high guess {high}"),
+            };
+        }
+        Verdict::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEDGER: &str = "# day9
+part2 too-low 39925070
+part2 too-low 107849020
+part2 too-high 2000000000
+part2 wrong 1098764625
+part2 unknown 1574684850
+";
+
+    fn rejected(reason: &str) -> Verdict {
+        Verdict::Rejected {
+            reason: reason.to_string(),
+        }
+    }
+
+    #[test]
+    fn check() {
+        let ledger = Ledger::parse(LEDGER).unwrap();
+        assert_eq!(5, ledger.guesses().len());
+        assert_eq!(
+            rejected("at or below too-low guess 107849020"),
+            ledger.check(Part::Two, "50000000")
+        );
+        assert_eq!(
+            rejected("at or above too-high guess 2000000000"),
+            ledger.check(Part::Two, "2000000001")
+        );
+        assert_eq!(
+            rejected("already guessed, wrong"),
+            ledger.check(Part::Two, "1098764625")
+        );
+        assert_eq!(Verdict::Unknown, ledger.check(Part::Two, "1574684850"));
+        assert_eq!(Verdict::Unknown, ledger.check(Part::One, "50000000"));
+    }
+
+    #[test]
+    fn correct() {
+        let ledger = Ledger::parse("part1 correct 7\npart1 too-low 3").unwrap();
+        assert_eq!(Verdict::Pass, ledger.check(Part::One, "7"));
+        assert_eq!(
+            Verdict::Fail {
+                expected: "7".to_string()
+            },
+            ledger.check(Part::One, "8")
+        );
+    }
+
+    #[test]
+    fn record() {
+        let path = std::env::temp_dir().join(format!("ledger-{}", std::process::id()));
+        let path = path.to_str().unwrap();
+        let guess = Guess {
+            part: Part::Two,
+            status: Status::TooHigh,
+            answer: "12".to_string(),
+        };
+        Ledger::record(path, &guess).unwrap();
+        Ledger::record(path, &guess).unwrap();
+        let ledger = Ledger::load(path).unwrap();
+        assert_eq!(vec![guess.clone(), guess], ledger.guesses());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(Ledger::parse("part2 39925070").is_err());
+        assert!(Ledger::parse("part3 wrong 1").is_err());
+        assert!(Ledger::parse("part2 close 1").is_err());
+    }
+}
@@ -3,16 +3,29 @@
 pub mod grid;
 pub mod point;
 
+mod answer;
+mod cancel;
+mod context;
+mod discover;
 //mod direction;
+pub mod expected;
 //mod fixedgrid;
 pub mod graph;
+mod isolation;
+pub mod ledger;
+pub mod logging;
 pub mod math;
+pub mod options;
+mod parse;
 //mod matrix;
 //mod picture;
 //mod point;
 mod region;
+pub mod report;
 mod runner;
+mod sidecar;
 mod solution;
+pub mod timing;
 //mod sparsegrid;
 
 //pub use point::Direction;
@@ -20,8 +33,15 @@ mod solution;
 //pub use matrix::Matrix;
 //pub use picture::Picture;
 //pub use point::Point;
-pub use runner::{log_init, run, BaseName};
-pub use solution::{load, Solution};
+pub use answer::Answer;
+pub use cancel::{CancellationToken, Cancelled};
+pub use context::RunContext;
+pub use discover::discover;
+pub use isolation::catch_panic;
+pub use logging::{log_init, LogGuard, LogOptions};
+pub use parse::{parse_field, ParseError};
+pub use runner::{check_reports, run, run_day, run_discovered, BaseName};
+pub use solution::{load, load_from_reader, load_from_str, Solution};
 //pub use sparsegrid::SparseGrid;
 
 #[macro_export]
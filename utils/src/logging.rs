@@ -0,0 +1,291 @@
+use std::{
+    env,
+    fs::{File, OpenOptions},
+    io::BufWriter,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use tracing::{info, warn};
+use tracing_subscriber::{
+    fmt::{format::FmtSpan, MakeWriter},
+    prelude::*,
+    EnvFilter, Layer,
+};
+
+/// Format of the span timings written when `ADVENT_TRACE` is set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    /// Chrome trace-event JSON, for `chrome://tracing` or Perfetto
+    Chrome,
+    /// Folded stacks, for `inferno-flamegraph` or speedscope
+    Folded,
+}
+
+impl TraceFormat {
+    fn default_path(self) -> &'static str {
+        match self {
+            TraceFormat::Chrome => "trace.json",
+            TraceFormat::Folded => "trace.folded",
+        }
+    }
+}
+
+/// Parse `chrome[:PATH]` or `folded[:PATH]`
+fn parse_trace(spec: &str) -> Result<(TraceFormat, PathBuf)> {
+    let (format, path) = spec.split_once(':').unwrap_or((spec, ""));
+    let format = match format {
+        "chrome" => TraceFormat::Chrome,
+        "folded" => TraceFormat::Folded,
+        _ => bail!("ADVENT_TRACE: expected 'chrome[:PATH]' or 'folded[:PATH]', not '{spec}'"),
+    };
+    let path = match path {
+        "" => format.default_path(),
+        path => path,
+    };
+    Ok((format, PathBuf::from(path)))
+}
+
+/// Held only for its `Drop`, which flushes the trace
+#[allow(dead_code)]
+enum TraceGuard {
+    Chrome(tracing_chrome::FlushGuard),
+    Folded(tracing_flame::FlushGuard<BufWriter<File>>),
+}
+
+/// Keeps any trace output open; it is flushed when dropped, so hold it until the end of `main`
+#[must_use = "trace output is only written when the guard is dropped"]
+pub struct LogGuard {
+    _trace: Option<TraceGuard>,
+}
+
+type BoxedLayer = Box<dyn Layer<tracing_subscriber::Registry> + Send + Sync>;
+
+fn trace_layer(format: TraceFormat, path: &Path) -> Result<(BoxedLayer, TraceGuard)> {
+    let context = || format!("creating trace file {path:?}");
+    Ok(match format {
+        TraceFormat::Chrome => {
+            let (layer, guard) = tracing_chrome::ChromeLayerBuilder::new()
+                .writer(File::create(path).with_context(context)?)
+                .include_args(true)
+                .build();
+            (layer.boxed(), TraceGuard::Chrome(guard))
+        }
+        TraceFormat::Folded => {
+            let (layer, guard) =
+                tracing_flame::FlameLayer::with_file(path).with_context(context)?;
+            (layer.boxed(), TraceGuard::Folded(guard))
+        }
+    })
+}
+
+/// How [`log_init`] logs, read from the environment by [`LogOptions::from_env`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogOptions {
+    /// Filter directives, e.g. `info,day12=trace,utils::runner=warn` (`RUST_LOG`)
+    pub filter: String,
+    /// Also log, without colour, to the end of this file (`ADVENT_LOG_FILE`)
+    pub log_file: Option<PathBuf>,
+    /// Show the thread name and id of each line
+    pub thread: bool,
+    /// Show the source file of each line
+    pub file: bool,
+    /// Show the source line number of each line
+    pub line: bool,
+    /// Record every span to a trace file (`ADVENT_TRACE`)
+    pub trace: Option<(TraceFormat, PathBuf)>,
+}
+
+impl Default for LogOptions {
+    fn default() -> Self {
+        Self {
+            filter: "info".to_string(),
+            log_file: None,
+            thread: true,
+            file: true,
+            line: true,
+            trace: None,
+        }
+    }
+}
+
+impl LogOptions {
+    /// Options from `RUST_LOG`, `ADVENT_LOG_FILE`, `ADVENT_TRACE` and `ADVENT_LOG_DECORATION`
+    /// (which of `thread`, `file` and `line` to show, comma separated)
+    pub fn from_env() -> Result<Self> {
+        Self::from_vars(|name| env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let mut options = Self::default();
+        if let Some(filter) = var("RUST_LOG") {
+            EnvFilter::try_new(&filter).map_err(|e| anyhow!("RUST_LOG '{filter}': {e}"))?;
+            options.filter = filter;
+        }
+        options.log_file = var("ADVENT_LOG_FILE").map(PathBuf::from);
+        options.trace = var("ADVENT_TRACE")
+            .map(|spec| parse_trace(&spec))
+            .transpose()?;
+        if let Some(decoration) = var("ADVENT_LOG_DECORATION") {
+            (options.thread, options.file, options.line) = (false, false, false);
+            for item in decoration
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+            {
+                match item {
+                    "thread" => options.thread = true,
+                    "file" => options.file = true,
+                    "line" => options.line = true,
+                    _ => {
+                        bail!("ADVENT_LOG_DECORATION: expected thread, file or line, not '{item}'")
+                    }
+                }
+            }
+        }
+        Ok(options)
+    }
+
+    /// Install the global subscriber. Only the first call (and only if no other subscriber is
+    /// installed) has any effect; later calls return an empty guard.
+    pub fn init(self) -> LogGuard {
+        static INITIALISED: AtomicBool = AtomicBool::new(false);
+        if INITIALISED.swap(true, Ordering::SeqCst) {
+            return LogGuard { _trace: None };
+        }
+
+        let mut layers = vec![self.fmt_layer(std::io::stderr, true)];
+        let mut failures = Vec::new();
+        if let Some(path) = &self.log_file {
+            match OpenOptions::new().create(true).append(true).open(path) {
+                Ok(file) => layers.push(self.fmt_layer(Mutex::new(file), false)),
+                Err(e) => failures.push(anyhow!(e).context(format!("opening log file {path:?}"))),
+            }
+        }
+        // Spans are traced at every level, whatever the filter says
+        let mut trace = None;
+        if let Some((format, path)) = &self.trace {
+            match trace_layer(*format, path) {
+                Ok((layer, guard)) => {
+                    layers.push(layer);
+                    trace = Some(guard);
+                }
+                Err(e) => failures.push(e.context("no trace recorded")),
+            }
+        }
+        if tracing_subscriber::registry()
+            .with(layers)
+            .try_init()
+            .is_err()
+        {
+            // e.g. a test's subscriber
+            return LogGuard { _trace: None };
+        }
+
+        for e in failures {
+            warn!("{e:#}");
+        }
+        if let (Some(_), Some((_, path))) = (&trace, &self.trace) {
+            info!("recording spans to {path:?}");
+        }
+        LogGuard { _trace: trace }
+    }
+
+    fn fmt_layer<W>(&self, writer: W, ansi: bool) -> BoxedLayer
+    where
+        W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+    {
+        tracing_subscriber::fmt::layer()
+            .with_span_events(FmtSpan::NONE)
+            .with_thread_ids(self.thread)
+            .with_thread_names(self.thread)
+            .with_file(self.file)
+            .with_line_number(self.line)
+            .with_ansi(ansi)
+            .with_writer(writer)
+            .with_filter(EnvFilter::builder().parse_lossy(&self.filter))
+            .boxed()
+    }
+}
+
+/// Set up logging from the environment (see [`LogOptions::from_env`]), falling back to the
+/// defaults if it is invalid. Safe to call more than once.
+pub fn log_init() -> LogGuard {
+    match LogOptions::from_env() {
+        Ok(options) => options.init(),
+        Err(e) => {
+            let guard = LogOptions::default().init();
+            warn!("{e:#}, logging with defaults");
+            guard
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_specs() {
+        assert_eq!(
+            (TraceFormat::Chrome, PathBuf::from("trace.json")),
+            parse_trace("chrome").unwrap()
+        );
+        assert_eq!(
+            (TraceFormat::Folded, PathBuf::from("/tmp/day12.folded")),
+            parse_trace("folded:/tmp/day12.folded").unwrap()
+        );
+        assert!(parse_trace("perf").is_err());
+    }
+
+    #[test]
+    fn options_from_vars() {
+        let vars = |pairs: &'static [(&str, &str)]| {
+            move |name: &str| {
+                pairs
+                    .iter()
+                    .find(|(k, _)| *k == name)
+                    .map(|(_, v)| v.to_string())
+            }
+        };
+        assert_eq!(
+            LogOptions::default(),
+            LogOptions::from_vars(vars(&[])).unwrap()
+        );
+
+        let options = LogOptions::from_vars(vars(&[
+            ("RUST_LOG", "warn,day12=trace"),
+            ("ADVENT_LOG_FILE", "day12.log"),
+            ("ADVENT_LOG_DECORATION", "line"),
+            ("ADVENT_TRACE", "folded"),
+        ]))
+        .unwrap();
+        assert_eq!("warn,day12=trace", options.filter);
+        assert_eq!(Some(PathBuf::from("day12.log")), options.log_file);
+        assert_eq!(
+            (false, false, true),
+            (options.thread, options.file, options.line)
+        );
+        assert_eq!(
+            Some((TraceFormat::Folded, PathBuf::from("trace.folded"))),
+            options.trace
+        );
+
+        assert!(LogOptions::from_vars(vars(&[("RUST_LOG", "day12=loud")])).is_err());
+        assert!(LogOptions::from_vars(vars(&[("ADVENT_LOG_DECORATION", "colour")])).is_err());
+    }
+
+    #[test]
+    fn init_twice() {
+        let quiet = || LogOptions {
+            filter: "off".to_string(),
+            ..LogOptions::default()
+        };
+        let _first = quiet().init();
+        let _second = quiet().init();
+    }
+}
@@ -0,0 +1,211 @@
+use std::{collections::BTreeMap, fmt::Display, io::Read, time::Duration};
+
+use anyhow::{bail, Context, Result};
+
+pub const USAGE: &str = "options:
+    --bench N        repeat each phase N times, reporting min/median/max
+    --json           print a JSON document per input run to stdout
+    --timeout SECS   abandon any part still running after SECS seconds, reporting TIMEOUT
+    --sample PATH    run against PATH as a sample input ('-' for stdin), instead of input/
+    --full PATH      run against PATH as a full input ('-' for stdin), instead of input/
+    --param K=V      set run parameter K for every input, overriding its .params sidecar";
+
+/// Where a run reads its input from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    File(String),
+    Stdin,
+}
+
+impl Input {
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Input::Stdin,
+            path => Input::File(path.to_string()),
+        }
+    }
+
+    /// Path that sidecar files (e.g. `.expected`) are stored alongside
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            Input::File(path) => Some(path),
+            Input::Stdin => None,
+        }
+    }
+
+    pub fn read(&self) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+        match self {
+            Input::File(path) => {
+                std::fs::File::open(path)?.read_to_end(&mut data)?;
+            }
+            Input::Stdin => {
+                std::io::stdin().read_to_end(&mut data)?;
+            }
+        }
+        Ok(data)
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Input::File(path) => write!(f, "{path}"),
+            Input::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// Command-line switches shared by every day's binary and `advent`
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    /// Number of times each phase is repeated
+    pub repeat: usize,
+    /// Print each run's report as a line of JSON
+    pub json: bool,
+    /// Budget for each part (covering all of its repetitions)
+    pub timeout: Option<Duration>,
+    /// Inputs replacing the day's default sample inputs
+    pub samples: Vec<Input>,
+    /// Inputs replacing the day's default full inputs
+    pub full: Vec<Input>,
+    /// Run parameters overriding those from each input's sidecar
+    pub params: BTreeMap<String, String>,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            repeat: 1,
+            json: false,
+            timeout: None,
+            samples: Vec::new(),
+            full: Vec::new(),
+            params: BTreeMap::new(),
+        }
+    }
+}
+
+impl RunOptions {
+    /// Parse recognised options, returning any remaining positional arguments
+    pub fn parse<I>(args: I) -> Result<(Self, Vec<String>)>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut options = Self::default();
+        let mut remaining = Vec::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .with_context(|| format!("{arg} requires a value\n{USAGE}"))
+            };
+            match arg.as_str() {
+                "--bench" => {
+                    options.repeat = value()?
+                        .parse()
+                        .with_context(|| format!("invalid --bench count\n{USAGE}"))?;
+                    if options.repeat == 0 {
+                        bail!("--bench count must be at least 1");
+                    }
+                }
+                "--json" => options.json = true,
+                "--timeout" => {
+                    let secs = value()?
+                        .parse::<f64>()
+                        .with_context(|| format!("invalid --timeout seconds\n{USAGE}"))?;
+                    options.timeout = Some(
+                        Duration::try_from_secs_f64(secs)
+                            .ok()
+                            .filter(|d| !d.is_zero())
+                            .context("--timeout must be a positive number of seconds")?,
+                    );
+                }
+                "--sample" => options.samples.push(Input::from_arg(&value()?)),
+                "--full" => options.full.push(Input::from_arg(&value()?)),
+                "--param" => {
+                    let param = value()?;
+                    let (name, value) = param
+                        .split_once('=')
+                        .with_context(|| format!("--param expects NAME=VALUE, not '{param}'"))?;
+                    options
+                        .params
+                        .insert(name.trim().to_string(), value.trim().to_string());
+                }
+                a if a.starts_with("--") => bail!("unknown option '{a}'\n{USAGE}"),
+                _ => remaining.push(arg),
+            }
+        }
+        let num_stdin = options
+            .samples
+            .iter()
+            .chain(options.full.iter())
+            .filter(|input| **input == Input::Stdin)
+            .count();
+        if num_stdin > 1 {
+            bail!("stdin ('-') can only be used as a single input");
+        }
+        Ok((options, remaining))
+    }
+
+    /// Whether inputs were given on the command line, replacing the defaults
+    pub fn has_inputs(&self) -> bool {
+        !self.samples.is_empty() || !self.full.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<(RunOptions, Vec<String>)> {
+        RunOptions::parse(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn bench() {
+        let (options, remaining) = parse(&["7", "--bench", "5"]).unwrap();
+        assert_eq!(5, options.repeat);
+        assert_eq!(vec!["7".to_string()], remaining);
+        assert_eq!(1, parse(&[]).unwrap().0.repeat);
+        assert!(parse(&["--bench"]).is_err());
+        assert!(parse(&["--bench", "0"]).is_err());
+        assert!(parse(&["--unknown"]).is_err());
+    }
+
+    #[test]
+    fn inputs() {
+        let (options, _) = parse(&["--sample", "a.txt", "--full", "-"]).unwrap();
+        assert_eq!(vec![Input::File("a.txt".to_string())], options.samples);
+        assert_eq!(vec![Input::Stdin], options.full);
+        assert!(options.has_inputs());
+        assert!(!parse(&[]).unwrap().0.has_inputs());
+        assert!(parse(&["--sample", "-", "--full", "-"]).is_err());
+    }
+
+    #[test]
+    fn json() {
+        assert!(!parse(&[]).unwrap().0.json);
+        assert!(parse(&["--json"]).unwrap().0.json);
+    }
+
+    #[test]
+    fn params() {
+        let (options, _) = parse(&["--param", "limit=10", "--param", "name = x"]).unwrap();
+        assert_eq!(Some("10"), options.params.get("limit").map(String::as_str));
+        assert_eq!(Some("x"), options.params.get("name").map(String::as_str));
+        assert!(parse(&["--param", "limit"]).is_err());
+    }
+
+    #[test]
+    fn timeout() {
+        assert_eq!(None, parse(&[]).unwrap().0.timeout);
+        assert_eq!(
+            Some(Duration::from_millis(1500)),
+            parse(&["--timeout", "1.5"]).unwrap().0.timeout
+        );
+        assert!(parse(&["--timeout", "0"]).is_err());
+        assert!(parse(&["--timeout", "-1"]).is_err());
+        assert!(parse(&["--timeout", "soon"]).is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Where, and why, an input line could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column (in chars) of the offending text
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error for `field` (a slice of `line`) on the line with 0-based index `id`
+    pub fn in_line(id: usize, line: &str, field: &str, message: impl Display) -> Self {
+        Self {
+            line: id + 1,
+            column: column(line, field),
+            text: field.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} ('{}')",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse `field` (a slice of `line`), reporting its position on failure
+pub fn parse_field<F>(id: usize, line: &str, field: &str) -> Result<F, ParseError>
+where
+    F: FromStr,
+    F::Err: Display,
+{
+    field
+        .trim()
+        .parse()
+        .map_err(|e| ParseError::in_line(id, line, field, e))
+}
+
+fn column(line: &str, field: &str) -> usize {
+    // Only meaningful when `field` really is a slice of `line`
+    let offset = (field.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    match offset.checked_add(field.len()) {
+        Some(end) if end <= line.len() => line
+            .get(..offset)
+            .map_or(1, |before| before.chars().count() + 1),
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_position() {
+        let line = "12,x4,7";
+        let field = line.split(',').nth(1).unwrap();
+        let e = parse_field::<u64>(2, line, field).unwrap_err();
+        assert_eq!(3, e.line);
+        assert_eq!(4, e.column);
+        assert_eq!("x4", e.text);
+        assert_eq!(
+            "line 3, column 4: invalid digit found in string ('x4')",
+            e.to_string()
+        );
+        assert_eq!(Ok(7u64), parse_field(2, line, "7"));
+    }
+
+    #[test]
+    fn unrelated_field() {
+        let e = ParseError::in_line(0, "abc", "elsewhere", "bad");
+        assert_eq!(1, e.column);
+    }
+}
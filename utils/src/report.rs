@@ -0,0 +1,313 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{expected::Verdict, timing::Timing};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Part {
+    #[serde(rename = "part1")]
+    One,
+    #[serde(rename = "part2")]
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "part1"),
+            Part::Two => write!(f, "part2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "part1" | "1" => Ok(Part::One),
+            "part2" | "2" => Ok(Part::Two),
+            _ => anyhow::bail!("unknown part '{s}', expected part1 or part2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Answer(String),
+    Error(String),
+    /// Abandoned after running over its budget, in milliseconds
+    Timeout(u64),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartReport {
+    pub part: Part,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+    pub verdict: Verdict,
+    pub timing: Timing,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunReport {
+    pub day: String,
+    #[serde(rename = "input")]
+    pub filename: String,
+    #[serde(rename = "full")]
+    pub is_full: bool,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+    /// Set when the input could not be loaded or analysed at all
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub load: Timing,
+    pub analyse: Timing,
+    pub parts: Vec<PartReport>,
+}
+
+impl RunReport {
+    pub fn failed(day: &str, filename: &str, is_full: bool, error: String) -> Self {
+        Self {
+            day: day.to_string(),
+            filename: filename.to_string(),
+            is_full,
+            params: BTreeMap::new(),
+            error: Some(error),
+            load: Timing::default(),
+            analyse: Timing::default(),
+            parts: Vec::new(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("reports are always serialisable")
+    }
+
+    pub fn num_failed(&self) -> usize {
+        self.parts.iter().filter(|p| p.verdict.is_failure()).count()
+    }
+}
+
+/// Render a plain-text table of every part's answer and verdict
+pub fn summary(reports: &[RunReport]) -> String {
+    let mut rows = vec![[
+        "day".to_string(),
+        "input".to_string(),
+        "part1".to_string(),
+        "part2".to_string(),
+    ]];
+    for report in reports {
+        let part = |part: Part| {
+            report
+                .parts
+                .iter()
+                .find(|p| p.part == part)
+                .map(|p| match &p.outcome {
+                    Outcome::Answer(answer) => {
+                        format!("{} {} ({})", answer, p.verdict.label(), p.timing)
+                    }
+                    Outcome::Error(_) => format!("error {} ({})", p.verdict.label(), p.timing),
+                    Outcome::Timeout(_) => {
+                        format!("TIMEOUT {} ({})", p.verdict.label(), p.timing)
+                    }
+                })
+                .unwrap_or_default()
+        };
+        let (part1, part2) = match report.error {
+            Some(_) => ("failed to load".to_string(), String::new()),
+            None => (part(Part::One), part(Part::Two)),
+        };
+        rows.push([report.day.clone(), report.filename.clone(), part1, part2]);
+    }
+
+    table(&rows)
+}
+
+/// Align `rows` (the first being the heading) into columns separated by `|`
+pub fn table<R: AsRef<[String]>>(rows: &[R]) -> String {
+    let mut widths = Vec::new();
+    for row in rows {
+        for (i, cell) in row.as_ref().iter().enumerate() {
+            if widths.len() <= i {
+                widths.push(0);
+            }
+            widths[i] = std::cmp::max(widths[i], cell.chars().count());
+        }
+    }
+    let mut table = String::new();
+    for row in rows {
+        let line = row
+            .as_ref()
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        table.push_str(line.trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+/// An answer that differs between two runs of the same day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub input: String,
+    pub part: Part,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let show = |v: &Option<String>| v.clone().unwrap_or_else(|| "-".to_string());
+        write!(
+            f,
+            "{} {}: {} -> {}",
+            self.input,
+            self.part,
+            show(&self.before),
+            show(&self.after)
+        )
+    }
+}
+
+/// Every (input, part) whose answer differs between `before` and `after`
+pub fn changes(before: &[RunReport], after: &[RunReport]) -> Vec<Change> {
+    let before = answers(before);
+    let mut after = answers(after);
+    let mut changes = Vec::new();
+    for ((input, part), old) in before {
+        let new = after.remove(&(input.clone(), part));
+        if new.as_ref() != Some(&old) {
+            changes.push(Change {
+                input,
+                part,
+                before: Some(old),
+                after: new,
+            });
+        }
+    }
+    for ((input, part), new) in after {
+        changes.push(Change {
+            input,
+            part,
+            before: None,
+            after: Some(new),
+        });
+    }
+    changes.sort_by(|a, b| (&a.input, a.part).cmp(&(&b.input, b.part)));
+    changes
+}
+
+fn answers(reports: &[RunReport]) -> BTreeMap<(String, Part), String> {
+    let mut answers = BTreeMap::new();
+    for report in reports {
+        if report.error.is_some() {
+            for part in [Part::One, Part::Two] {
+                answers.insert(
+                    (report.filename.clone(), part),
+                    "failed to load".to_string(),
+                );
+            }
+        }
+        for p in &report.parts {
+            let answer = match &p.outcome {
+                Outcome::Answer(answer) => answer.clone(),
+                Outcome::Error(_) => "error".to_string(),
+                Outcome::Timeout(_) => "TIMEOUT".to_string(),
+            };
+            answers.insert((report.filename.clone(), p.part), answer);
+        }
+    }
+    answers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_round_trip() {
+        let report = RunReport {
+            day: "day1".to_string(),
+            filename: "input/day1.sample".to_string(),
+            is_full: false,
+            params: BTreeMap::new(),
+            error: None,
+            load: Timing::default(),
+            analyse: Timing::default(),
+            parts: vec![
+                PartReport {
+                    part: Part::One,
+                    outcome: Outcome::Answer("3".to_string()),
+                    verdict: Verdict::Fail {
+                        expected: "4".to_string(),
+                    },
+                    timing: Timing::default(),
+                },
+                PartReport {
+                    part: Part::Two,
+                    outcome: Outcome::Error("part2 failed".to_string()),
+                    verdict: Verdict::Unknown,
+                    timing: Timing::default(),
+                },
+            ],
+        };
+        let json = report.to_json();
+        assert!(json.contains(r#""input":"input/day1.sample""#));
+        assert!(json
+            .contains(r#""part":"part1","answer":"3","verdict":{"status":"fail","expected":"4"}"#));
+        assert!(json.contains(r#""part":"part2","error":"part2 failed""#));
+
+        let parsed: RunReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(report.parts[0].outcome, parsed.parts[0].outcome);
+        assert_eq!(report.parts[0].verdict, parsed.parts[0].verdict);
+        assert_eq!(report.parts[1].outcome, parsed.parts[1].outcome);
+        assert_eq!(1, parsed.num_failed());
+    }
+
+    fn report(filename: &str, part1: Outcome) -> RunReport {
+        RunReport {
+            day: "day1".to_string(),
+            filename: filename.to_string(),
+            is_full: false,
+            params: BTreeMap::new(),
+            error: None,
+            load: Timing::default(),
+            analyse: Timing::default(),
+            parts: vec![PartReport {
+                part: Part::One,
+                outcome: part1,
+                verdict: Verdict::Unknown,
+                timing: Timing::default(),
+            }],
+        }
+    }
+
+    #[test]
+    fn answer_changes() {
+        let before = [
+            report("a", Outcome::Answer("1".to_string())),
+            report("b", Outcome::Answer("2".to_string())),
+            report("c", Outcome::Answer("3".to_string())),
+        ];
+        let after = [
+            report("a", Outcome::Answer("1".to_string())),
+            report("b", Outcome::Error("part1 failed".to_string())),
+            report("d", Outcome::Answer("4".to_string())),
+        ];
+        let changes = changes(&before, &after)
+            .iter()
+            .map(Change::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["b part1: 2 -> error", "c part1: 3 -> -", "d part1: - -> 4"],
+            changes
+        );
+        assert!(super::changes(&after, &after).is_empty());
+    }
+}
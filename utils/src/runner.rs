@@ -1,11 +1,30 @@
-use std::{env, fmt::Display, str::FromStr};
+use std::{
+    env,
+    fmt::Display,
+    path::Path,
+    sync::{mpsc, Arc},
+    thread,
+    time::Duration,
+};
 
-use anyhow::{Context, Result};
-use tracing::{error, info, instrument, span, Level};
-use tracing_subscriber::fmt::format::FmtSpan;
+use anyhow::{anyhow, bail, Context, Result};
+use tracing::{error, info, instrument, span, warn, Level, Span};
 use yansi::Paint;
 
-use crate::{load, Solution};
+use crate::{
+    answer::Answer,
+    cancel::CancellationToken,
+    discover::discover,
+    expected::{Expected, Verdict},
+    isolation::catch_panic,
+    ledger::Ledger,
+    load_from_reader,
+    options::{Input, RunOptions, USAGE},
+    report::{Outcome, Part, PartReport, RunReport},
+    sidecar,
+    timing::Timing,
+    ParseError, RunContext, Solution,
+};
 
 pub trait BaseName {
     fn base_name(&self) -> Self;
@@ -17,30 +36,49 @@ impl BaseName for &str {
     }
 }
 
-pub fn log_init() {
-    // install global collector configured based on RUST_LOG env var.
-    let level =
-        env::var("RUST_LOG").map_or(Level::INFO, |v| Level::from_str(&v).unwrap_or(Level::INFO));
-    tracing_subscriber::fmt()
-        .with_span_events(FmtSpan::NONE)
-        .with_thread_ids(true)
-        .with_thread_names(true)
-        .with_file(true)
-        .with_line_number(true)
-        .with_max_level(level)
-        .init();
+/// Run against every `input/{basename}.sample*` and `input/{basename}.full*` file
+pub fn run_discovered<S>() -> Result<()>
+where
+    S: Solution
+        + for<'a> TryFrom<std::io::BufReader<&'a [u8]>, Error = ParseError>
+        + std::fmt::Debug
+        + Send
+        + Sync
+        + 'static,
+    S::Part1: Answer + Send + 'static,
+    S::Part2: Answer + Send + 'static,
+{
+    let basename = basename();
+    let (samples, full) = discover(Path::new("input"), &basename)?;
+    let options = parse_options()?;
+    if samples.is_empty() && full.is_empty() && !options.has_inputs() {
+        bail!("no inputs found: expected input/{basename}.sample* or input/{basename}.full*");
+    }
+    let samples = samples.iter().map(String::as_str).collect::<Vec<_>>();
+    let full = full.iter().map(String::as_str).collect::<Vec<_>>();
+    run::<S>(&samples, &full)
 }
 
-#[instrument]
-pub fn run<S, R>(samples: &[&str], full: &[&str]) -> Result<()>
+/// Run against `input/{basename}.{suffix}` for exactly the given sample and full suffixes
+pub fn run<S>(samples: &[&str], full: &[&str]) -> Result<()>
 where
     S: Solution
-        + TryFrom<std::io::BufReader<std::fs::File>, Error = std::io::Error>
-        + std::fmt::Debug,
-    S::Result: Context<R, anyhow::Error>,
-    R: Display,
+        + for<'a> TryFrom<std::io::BufReader<&'a [u8]>, Error = ParseError>
+        + std::fmt::Debug
+        + Send
+        + Sync
+        + 'static,
+    S::Part1: Answer + Send + 'static,
+    S::Part2: Answer + Send + 'static,
 {
-    let basename = std::env::current_exe()
+    let options = parse_options()?;
+    let reports = run_day::<S>(&basename(), samples, full, &options);
+    check_reports(&reports)
+}
+
+/// Name of the running day, from its executable
+fn basename() -> String {
+    std::env::current_exe()
         .ok()
         .unwrap()
         .file_name()
@@ -48,66 +86,332 @@ where
         .to_str()
         .map(|s| s.base_name())
         .unwrap()
-        .to_owned();
+        .to_owned()
+}
+
+fn parse_options() -> Result<RunOptions> {
+    let (options, remaining) = RunOptions::parse(env::args().skip(1))?;
+    if let Some(arg) = remaining.first() {
+        bail!("unexpected argument '{arg}'\n{USAGE}");
+    }
+    Ok(options)
+}
+
+/// Run a single day's solution against `input/{basename}.{suffix}` for each sample and full suffix,
+/// or against the inputs given in `options` instead
+#[instrument(skip(options))]
+pub fn run_day<S>(
+    basename: &str,
+    samples: &[&str],
+    full: &[&str],
+    options: &RunOptions,
+) -> Vec<RunReport>
+where
+    S: Solution
+        + for<'a> TryFrom<std::io::BufReader<&'a [u8]>, Error = ParseError>
+        + std::fmt::Debug
+        + Send
+        + Sync
+        + 'static,
+    S::Part1: Answer + Send + 'static,
+    S::Part2: Answer + Send + 'static,
+{
+    let (samples, full) = if options.has_inputs() {
+        (options.samples.clone(), options.full.clone())
+    } else {
+        let inputs = |suffixes: &[&str]| {
+            suffixes
+                .iter()
+                .map(|suffix| Input::File(format!("input/{basename}.{suffix}")))
+                .collect::<Vec<_>>()
+        };
+        (inputs(samples), inputs(full))
+    };
+    if samples.is_empty() && full.is_empty() {
+        warn!("{}no inputs for {}", Paint::mask("🎄 "), basename);
+    }
 
+    let mut reports = Vec::new();
     span!(Level::INFO, "samples").in_scope(|| {
-        for suffix in samples.iter() {
-            let filename = format!("input/{basename}.{suffix}");
-            if let Err(e) = run_solution_file::<S, R>(&filename, false) {
-                error!(
-                    "{}Failed running against '{}': {:?}",
-                    Paint::mask("🎄 "),
-                    filename,
-                    e
-                );
-            }
+        for input in samples.iter() {
+            let report =
+                run_solution_file::<S>(basename, input, false, options).unwrap_or_else(|e| {
+                    error!(
+                        "{}Failed running against '{}': {:?}",
+                        Paint::mask("🎄 "),
+                        input,
+                        e
+                    );
+                    RunReport::failed(basename, &input.to_string(), false, format!("{e:#}"))
+                });
+            emit(&report, options);
+            reports.push(report);
         }
     });
     span!(Level::INFO, "full").in_scope(|| {
-        for suffix in full.iter() {
-            let filename = format!("input/{basename}.{suffix}");
-            if let Err(e) = run_solution_file::<S, R>(&filename, true) {
-                error!(
-                    "{}Failed running against '{}': {:?}",
-                    Paint::mask("🎅 "),
-                    filename,
-                    e
-                );
-            }
+        for input in full.iter() {
+            let report =
+                run_solution_file::<S>(basename, input, true, options).unwrap_or_else(|e| {
+                    error!(
+                        "{}Failed running against '{}': {:?}",
+                        Paint::mask("🎅 "),
+                        input,
+                        e
+                    );
+                    RunReport::failed(basename, &input.to_string(), true, format!("{e:#}"))
+                });
+            emit(&report, options);
+            reports.push(report);
         }
     });
+    reports
+}
+
+fn emit(report: &RunReport, options: &RunOptions) {
+    if options.json {
+        println!("{}", report.to_json());
+    }
+}
+
+/// Fail if any answer did not match its expected value
+pub fn check_reports(reports: &[RunReport]) -> Result<()> {
+    let num_failed = reports.iter().map(RunReport::num_failed).sum::<usize>();
+    if num_failed > 0 {
+        bail!("{num_failed} answer(s) did not match expected");
+    }
     Ok(())
 }
 
-fn run_solution_file<S, R>(filename: &str, is_full: bool) -> Result<()>
+fn run_solution_file<S>(
+    day: &str,
+    input: &Input,
+    is_full: bool,
+    options: &RunOptions,
+) -> Result<RunReport>
 where
     S: Solution
-        + TryFrom<std::io::BufReader<std::fs::File>, Error = std::io::Error>
-        + std::fmt::Debug,
-    S::Result: Context<R, anyhow::Error>,
-    R: Display,
+        + for<'a> TryFrom<std::io::BufReader<&'a [u8]>, Error = ParseError>
+        + std::fmt::Debug
+        + Send
+        + Sync
+        + 'static,
+    S::Part1: Answer + Send + 'static,
+    S::Part2: Answer + Send + 'static,
 {
-    let mut solution = load::<S>(filename)?;
-    solution.analyse(is_full);
+    let (expected, mut params) = match input.path() {
+        Some(path) => (Expected::load(path)?, sidecar::load(path, "params")?),
+        None => Default::default(),
+    };
+    params.extend(options.params.clone());
+    // Guesses are only ever made for the full input
+    let ledger = if is_full {
+        Ledger::load(&Ledger::path(day))?
+    } else {
+        Ledger::default()
+    };
+    let ctx = RunContext::new(is_full, params);
+    let data = input.read()?;
+    let mut load_timing = Timing::default();
+    let mut analyse_timing = Timing::default();
+    let mut solution = None;
+    // analyse() mutates the solution, so each repetition starts from a fresh load
+    for _ in 0..options.repeat {
+        let mut s = load_timing
+            .measure(|| catch_panic(|| load_from_reader::<S, _>(&data[..])))
+            .context("load failed")?
+            .with_context(|| format!("malformed input '{input}'"))?;
+        analyse_timing
+            .measure(|| catch_panic(|| s.analyse(&ctx)))
+            .context("analyse failed")?;
+        solution = Some(s);
+    }
+    let solution = Arc::new(solution.context("no repetitions")?);
     info!(
-        "{}{} {}: {:?}",
+        "{}{} {}: {:?} (load {}, analyse {})",
         Paint::mask("🎄 "),
-        Paint::bold(&Paint::green(filename)),
+        Paint::bold(&Paint::green(&input)),
         Paint::bold(&Paint::yellow("solution")),
-        solution
+        solution,
+        load_timing,
+        analyse_timing
     );
-    span!(Level::INFO, "part1").in_scope(|| {
-        match &solution.answer_part1(is_full).context("part1 failed") {
-            Ok(r) => info!("{}part1 answer is {}", Paint::mask("🎅 "), r),
-            Err(e) => error!("{}part1 failed: {}", Paint::mask("🎅 "), e),
-        }
+    if !ctx.params().is_empty() {
+        info!("{}params {:?}", Paint::mask("🎄 "), ctx.params());
+    }
+    let part1 = span!(Level::INFO, "part1").in_scope(|| {
+        let (answer, timing) = run_part(&solution, &ctx, options, |s, ctx| s.answer_part1(ctx));
+        report_part(
+            Part::One,
+            flatten(Part::One, answer),
+            timing,
+            &expected,
+            &ledger,
+        )
     });
-    span!(Level::INFO, "part2").in_scope(|| {
-        match &solution.answer_part2(is_full).context("part2 failed") {
-            Ok(r) => info!("{}part2 answer is {}", Paint::mask("🎅 "), r),
-            Err(e) => error!("{}part2 failed: {}", Paint::mask("🎅 "), e),
-        }
+    let part2 = span!(Level::INFO, "part2").in_scope(|| {
+        let (answer, timing) = run_part(&solution, &ctx, options, |s, ctx| s.answer_part2(ctx));
+        report_part(
+            Part::Two,
+            flatten(Part::Two, answer),
+            timing,
+            &expected,
+            &ledger,
+        )
     });
 
-    Ok(())
+    Ok(RunReport {
+        day: day.to_string(),
+        filename: input.to_string(),
+        is_full,
+        params: ctx.params().clone(),
+        error: None,
+        load: load_timing,
+        analyse: analyse_timing,
+        parts: vec![part1, part2],
+    })
+}
+
+/// A part that was abandoned after running over its budget
+#[derive(Debug)]
+struct TimedOut(Duration);
+
+impl Display for TimedOut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "timed out after {:.2?}", self.0)
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+/// Run every repetition of a part, on a worker thread when it has a time budget.
+///
+/// A part still running when the budget expires has its context's token cancelled and is left
+/// behind; parts which never poll the token keep running until the process exits.
+fn run_part<S, T>(
+    solution: &Arc<S>,
+    ctx: &RunContext,
+    options: &RunOptions,
+    answer: impl Fn(&S, &RunContext) -> T + Send + 'static,
+) -> (Result<T>, Timing)
+where
+    S: Send + Sync + 'static,
+    T: Send + 'static,
+{
+    let token = CancellationToken::new();
+    let ctx = ctx.with_cancellation(token.clone());
+    let Some(budget) = options.timeout else {
+        return Timing::repeat(options.repeat, || catch_panic(|| answer(solution, &ctx)));
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let worker = (Arc::clone(solution), ctx, Span::current());
+    let repeat = options.repeat;
+    let spawned = thread::Builder::new()
+        .name(format!(
+            "{}-worker",
+            thread::current().name().unwrap_or("part")
+        ))
+        .spawn(move || {
+            let (solution, ctx, span) = worker;
+            let _span = span.enter();
+            // The receiver is gone if the part timed out
+            let _ = tx.send(Timing::repeat(repeat, || {
+                catch_panic(|| answer(&solution, &ctx))
+            }));
+        });
+    if let Err(e) = spawned {
+        return (
+            Err(anyhow!(e).context("could not start part")),
+            Timing::default(),
+        );
+    }
+    match rx.recv_timeout(budget) {
+        Ok(r) => r,
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            token.cancel();
+            (Err(TimedOut(budget).into()), Timing::once(budget))
+        }
+        Err(mpsc::RecvTimeoutError::Disconnected) => (
+            Err(anyhow!("part exited without an answer")),
+            Timing::default(),
+        ),
+    }
+}
+
+/// Merge a caught panic with the part's own error
+fn flatten<T>(part: Part, answer: Result<T>) -> Result<String>
+where
+    T: Answer,
+{
+    answer
+        .and_then(Answer::into_answer)
+        .with_context(|| format!("{part} failed"))
+}
+
+fn report_part(
+    part: Part,
+    answer: Result<String>,
+    timing: Timing,
+    expected: &Expected,
+    ledger: &Ledger,
+) -> PartReport {
+    let (outcome, verdict) = match answer {
+        Ok(answer) => {
+            let verdict = match expected.check(part, &answer) {
+                Verdict::Unknown => ledger.check(part, &answer),
+                verdict => verdict,
+            };
+            match verdict {
+                ref v if v.is_failure() => error!(
+                    "{}{} answer is {} {} ({})",
+                    Paint::mask("🎅 "),
+                    part,
+                    answer,
+                    verdict,
+                    timing
+                ),
+                _ => info!(
+                    "{}{} answer is {} {} ({})",
+                    Paint::mask("🎅 "),
+                    part,
+                    answer,
+                    verdict,
+                    timing
+                ),
+            }
+            (Outcome::Answer(answer), verdict)
+        }
+        Err(e) => {
+            // A known answer that can no longer be produced is a regression
+            let verdict = match expected.get(part).or(ledger.correct(part)) {
+                Some(v) => Verdict::Fail {
+                    expected: v.to_string(),
+                },
+                None => Verdict::Unknown,
+            };
+            match e.downcast_ref::<TimedOut>() {
+                Some(TimedOut(budget)) => {
+                    warn!(
+                        "{}{} TIMEOUT {} ({})",
+                        Paint::mask("🎅 "),
+                        part,
+                        verdict,
+                        timing
+                    );
+                    (Outcome::Timeout(budget.as_millis() as u64), verdict)
+                }
+                None => {
+                    let e = format!("{e:#}");
+                    error!("{}{} {} ({})", Paint::mask("🎅 "), e, verdict, timing);
+                    (Outcome::Error(e), verdict)
+                }
+            }
+        }
+    };
+    PartReport {
+        part,
+        outcome,
+        verdict,
+        timing,
+    }
 }
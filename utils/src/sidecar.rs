@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+
+pub type Sidecar = BTreeMap<String, String>;
+
+/// Path of the sidecar file stored alongside `input`, e.g. `input/day5.sample.expected`
+pub fn path(input: &str, extension: &str) -> String {
+    format!("{input}.{extension}")
+}
+
+/// Read `key = value` lines from the sidecar of `input`, if there is one
+pub fn load(input: &str, extension: &str) -> Result<Sidecar> {
+    let filename = path(input, extension);
+    match std::fs::read_to_string(&filename) {
+        Ok(contents) => parse(&contents).with_context(|| format!("reading '{filename}'")),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Sidecar::new()),
+        Err(e) => Err(e).with_context(|| format!("reading '{filename}'")),
+    }
+}
+
+pub fn parse(contents: &str) -> Result<Sidecar> {
+    let mut values = Sidecar::new();
+    for (id, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match line.split_once('=') {
+            Some((key, value)) => {
+                values.insert(key.trim().to_string(), value.trim().to_string());
+            }
+            None => bail!("line {}: expected 'key = value', got '{}'", id + 1, line),
+        }
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_values() {
+        let values = parse("# known answers\npart1 = 3\n\npart2=  6 \n").unwrap();
+        assert_eq!(2, values.len());
+        assert_eq!(Some("3"), values.get("part1").map(String::as_str));
+        assert_eq!(Some("6"), values.get("part2").map(String::as_str));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(parse("part1 3").is_err());
+    }
+}
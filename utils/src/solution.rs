@@ -1,17 +1,39 @@
+use std::io::{BufReader, Read};
+
+use anyhow::Context;
+
+use crate::{ParseError, RunContext};
+
 pub trait Solution {
-    type Result;
+    /// Part answers, usually `anyhow::Result<T>` for any `Display` type `T` (see [`crate::Answer`])
+    type Part1;
+    type Part2;
 
-    fn analyse(&mut self, is_full: bool);
+    fn analyse(&mut self, ctx: &RunContext);
 
-    fn answer_part1(&self, is_full: bool) -> Self::Result;
-    fn answer_part2(&self, is_full: bool) -> Self::Result;
+    fn answer_part1(&self, ctx: &RunContext) -> Self::Part1;
+    fn answer_part2(&self, ctx: &RunContext) -> Self::Part2;
 }
 
-pub fn load<T: Solution + TryFrom<std::io::BufReader<std::fs::File>, Error = std::io::Error>>(
+pub fn load<T: Solution + TryFrom<BufReader<std::fs::File>, Error = ParseError>>(
     filename: &str,
-) -> std::io::Result<T> {
+) -> anyhow::Result<T> {
     let file = std::fs::File::open(filename)?;
 
-    let reader = std::io::BufReader::new(file);
-    T::try_from(reader)
+    load_from_reader(file).with_context(|| format!("malformed input '{filename}'"))
+}
+
+pub fn load_from_reader<T, R>(reader: R) -> Result<T, ParseError>
+where
+    T: Solution + TryFrom<BufReader<R>, Error = ParseError>,
+    R: Read,
+{
+    T::try_from(BufReader::new(reader))
+}
+
+pub fn load_from_str<T>(input: &str) -> Result<T, ParseError>
+where
+    T: Solution + for<'a> TryFrom<BufReader<&'a [u8]>, Error = ParseError>,
+{
+    load_from_reader(input.as_bytes())
 }
@@ -0,0 +1,148 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Wall-clock durations of every repetition of a phase
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "TimingJson", from = "TimingJson")]
+pub struct Timing {
+    samples: Vec<Duration>,
+}
+
+/// Serialised form of [`Timing`], in nanoseconds
+#[derive(Serialize, Deserialize)]
+struct TimingJson {
+    samples_ns: Vec<u64>,
+    #[serde(default)]
+    min_ns: Option<u64>,
+    #[serde(default)]
+    median_ns: Option<u64>,
+    #[serde(default)]
+    max_ns: Option<u64>,
+}
+
+impl From<Timing> for TimingJson {
+    fn from(timing: Timing) -> Self {
+        let nanos = |d: Duration| d.as_nanos() as u64;
+        Self {
+            min_ns: timing.min().map(nanos),
+            median_ns: timing.median().map(nanos),
+            max_ns: timing.max().map(nanos),
+            samples_ns: timing.samples.into_iter().map(nanos).collect(),
+        }
+    }
+}
+
+impl From<TimingJson> for Timing {
+    fn from(json: TimingJson) -> Self {
+        Self {
+            samples: json
+                .samples_ns
+                .into_iter()
+                .map(Duration::from_nanos)
+                .collect(),
+        }
+    }
+}
+
+impl Timing {
+    pub fn once(duration: Duration) -> Self {
+        Self {
+            samples: vec![duration],
+        }
+    }
+
+    pub fn measure<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let r = f();
+        self.samples.push(start.elapsed());
+        r
+    }
+
+    /// Run `f` `count` times (at least once), returning the last result
+    pub fn repeat<T>(count: usize, mut f: impl FnMut() -> T) -> (T, Self) {
+        let mut timing = Self::default();
+        let mut r = timing.measure(&mut f);
+        for _ in 1..count {
+            r = timing.measure(&mut f);
+        }
+        (r, timing)
+    }
+
+    pub fn samples(&self) -> &[Duration] {
+        &self.samples
+    }
+
+    pub fn min(&self) -> Option<Duration> {
+        self.samples.iter().min().copied()
+    }
+
+    pub fn max(&self) -> Option<Duration> {
+        self.samples.iter().max().copied()
+    }
+
+    pub fn median(&self) -> Option<Duration> {
+        let mut sorted = self.samples.clone();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        match sorted.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(sorted[mid]),
+            _ => Some((sorted[mid - 1] + sorted[mid]) / 2),
+        }
+    }
+}
+
+impl Display for Timing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.samples.len(), self.min(), self.median(), self.max()) {
+            (0, ..) => write!(f, "-"),
+            (1, _, Some(median), _) => write!(f, "{median:.2?}"),
+            (n, Some(min), Some(median), Some(max)) => write!(
+                f,
+                "min {min:.2?}, median {median:.2?}, max {max:.2?} over {n} runs"
+            ),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(millis: &[u64]) -> Timing {
+        Timing {
+            samples: millis.iter().map(|v| Duration::from_millis(*v)).collect(),
+        }
+    }
+
+    #[test]
+    fn statistics() {
+        let t = timing(&[5, 1, 3]);
+        assert_eq!(Some(Duration::from_millis(1)), t.min());
+        assert_eq!(Some(Duration::from_millis(3)), t.median());
+        assert_eq!(Some(Duration::from_millis(5)), t.max());
+        assert_eq!(
+            Some(Duration::from_millis(3)),
+            timing(&[4, 1, 2, 5]).median()
+        );
+        assert_eq!(None, timing(&[]).median());
+    }
+
+    #[test]
+    fn repeat() {
+        let mut calls = 0;
+        let (r, t) = Timing::repeat(3, || {
+            calls += 1;
+            calls
+        });
+        assert_eq!(3, r);
+        assert_eq!(3, t.samples().len());
+        let (_, t) = Timing::repeat(0, || ());
+        assert_eq!(1, t.samples().len());
+    }
+}
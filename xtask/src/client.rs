@@ -0,0 +1,55 @@
+use anyhow::{bail, Result as AnyResult};
+
+use crate::config::Config;
+
+/// Minimal client for the puzzle site, authenticated with the session cookie
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(&config.user_agent)
+            .build();
+        Self { config, agent }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// The full puzzle input for `day`
+    pub fn input(&self, day: u32) -> AnyResult<String> {
+        self.get(&format!("{}/input", self.config.day_url(day)))
+    }
+
+    pub fn get(&self, url: &str) -> AnyResult<String> {
+        let request = self.agent.get(url).set("Cookie", &self.cookie()?);
+        Self::body(url, request.call())
+    }
+
+    pub fn post_form(&self, url: &str, form: &[(&str, &str)]) -> AnyResult<String> {
+        let request = self.agent.post(url).set("Cookie", &self.cookie()?);
+        Self::body(url, request.send_form(form))
+    }
+
+    fn cookie(&self) -> AnyResult<String> {
+        Ok(format!("session={}", self.config.session()?))
+    }
+
+    fn body(url: &str, response: Result<ureq::Response, ureq::Error>) -> AnyResult<String> {
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                bail!(
+                    "{url}: HTTP {code}: {}",
+                    body.lines().next().unwrap_or_default()
+                )
+            }
+            Err(e) => bail!("{url}: {e}"),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use anyhow::{Context, Result as AnyResult};
+use std::env;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u32 = 2025;
+const DEFAULT_USER_AGENT: &str = "github.com/SMartinScottLogic/advent_2025 xtask";
+
+/// Settings for talking to the puzzle site, read from `aoc.toml` (or `$AOC_CONFIG`).
+///
+/// `AOC_SESSION` and `AOC_BASE_URL` override the file, so the token need not be stored on disk.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub year: u32,
+    pub user_agent: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            year: DEFAULT_YEAR,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> AnyResult<Self> {
+        let path = env::var("AOC_CONFIG").unwrap_or_else(|_| "aoc.toml".to_string());
+        let mut config = match std::fs::read_to_string(&path) {
+            Ok(contents) => Self::parse(&contents).with_context(|| format!("reading '{path}'"))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => return Err(e).with_context(|| format!("reading '{path}'")),
+        };
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+
+    pub fn parse(contents: &str) -> AnyResult<Self> {
+        let toml = contents.parse::<toml_edit::DocumentMut>()?;
+        let string = |key: &str| toml.get(key).and_then(|v| v.as_str()).map(str::to_string);
+        let mut config = Self {
+            session: string("session"),
+            ..Self::default()
+        };
+        if let Some(base_url) = string("base_url") {
+            config.base_url = base_url;
+        }
+        if let Some(user_agent) = string("user_agent") {
+            config.user_agent = user_agent;
+        }
+        if let Some(year) = toml.get("year") {
+            config.year = year
+                .as_integer()
+                .and_then(|year| u32::try_from(year).ok())
+                .context("year must be a positive integer")?;
+        }
+        Ok(config)
+    }
+
+    pub fn session(&self) -> AnyResult<&str> {
+        self.session
+            .as_deref()
+            .context("no session token: set 'session' in aoc.toml or AOC_SESSION")
+    }
+
+    /// URL of the puzzle page for `day`
+    pub fn day_url(&self, day: u32) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url.trim_end_matches('/'),
+            self.year,
+            day
+        )
+    }
+}
+
+/// Puzzle number of a day crate name, e.g. 7 for `day7`
+pub fn day_number(name: &str) -> AnyResult<u32> {
+    name.strip_prefix("day")
+        .and_then(|n| n.parse().ok())
+        .filter(|n| (1..=25).contains(n))
+        .with_context(|| format!("expected a day name like 'day7', not '{name}'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let config = Config::parse(
+            "session = \"abc\"\nbase_url = \"http://127.0.0.1:8080/\"\nyear = 2024\n",
+        )
+        .unwrap();
+        assert_eq!(Some("abc"), config.session.as_deref());
+        assert_eq!("http://127.0.0.1:8080/2024/day/7", config.day_url(7));
+        assert_eq!(DEFAULT_YEAR, Config::parse("").unwrap().year);
+        assert!(Config::parse("").unwrap().session().is_err());
+        assert!(Config::parse("year = -1").is_err());
+    }
+
+    #[test]
+    fn day_numbers() {
+        assert_eq!(7, day_number("day7").unwrap());
+        assert!(day_number("day0").is_err());
+        assert!(day_number("template").is_err());
+    }
+}
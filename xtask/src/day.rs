@@ -0,0 +1,35 @@
+use anyhow::{bail, Context, Result as AnyResult};
+use std::{
+    env,
+    process::{Command, Stdio},
+};
+use utils::report::RunReport;
+
+/// Build and run a day with `--json`, collecting its reports (the day's logs go to stderr)
+pub fn run(day: &str, args: &[String]) -> AnyResult<Vec<RunReport>> {
+    run_with(day, &[], args)
+}
+
+/// As [`run`], passing `cargo_args` (e.g. `--release`) to `cargo run`
+pub fn run_with(day: &str, cargo_args: &[&str], args: &[String]) -> AnyResult<Vec<RunReport>> {
+    let output = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args(["run", "--quiet", "--package", day])
+        .args(cargo_args)
+        .args(["--", "--json"])
+        .args(args)
+        .env(
+            "RUST_LOG",
+            env::var("RUST_LOG").unwrap_or_else(|_| "warn".to_string()),
+        )
+        .stderr(Stdio::inherit())
+        .output()
+        .context("failed to run cargo")?;
+    let reports = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RunReport>(line).ok())
+        .collect::<Vec<_>>();
+    if reports.is_empty() && !output.status.success() {
+        bail!("'{day}' failed to build or run");
+    }
+    Ok(reports)
+}
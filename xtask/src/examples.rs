@@ -0,0 +1,153 @@
+use anyhow::{bail, Context, Result as AnyResult};
+use std::{env, path::Path};
+
+use crate::{
+    client::Client,
+    config::{day_number, Config},
+    html,
+};
+
+const USAGE: &str =
+    "usage: cargo xtask examples dayN [--html PATH] [--refresh] [--write K,...] [--force]
+    --html PATH    read the puzzle page from PATH instead of input/dayN.html
+    --refresh      download the puzzle page again, e.g. once part 2 is available
+    --write K,...  write code blocks K,... (as listed) to input/dayN.sample1, ...
+    --force        overwrite existing sample files";
+
+/// Convert a day's puzzle page to Markdown, list its code blocks and save chosen ones as samples
+pub fn examples() -> AnyResult<()> {
+    let mut args = env::args().skip(2);
+    let day = args.next().context(USAGE)?;
+    let mut html_path = None;
+    let mut refresh = false;
+    let mut chosen = Vec::new();
+    let mut force = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--html" => html_path = Some(args.next().context(USAGE)?),
+            "--refresh" => refresh = true,
+            "--write" => {
+                for k in args.next().context(USAGE)?.split(',') {
+                    chosen.push(
+                        k.trim()
+                            .parse::<usize>()
+                            .with_context(|| format!("invalid block number '{k}'\n{USAGE}"))?,
+                    );
+                }
+            }
+            "--force" => force = true,
+            _ => bail!("unknown argument '{arg}'\n{USAGE}"),
+        }
+    }
+
+    let dir = Path::new("input");
+    std::fs::create_dir_all(dir)?;
+    let html = match html_path {
+        Some(path) => {
+            std::fs::read_to_string(&path).with_context(|| format!("reading '{path}'"))?
+        }
+        None => puzzle_page(&day, dir, refresh)?,
+    };
+
+    let markdown = dir.join(format!("{day}.md"));
+    std::fs::write(&markdown, to_markdown(&html))?;
+    println!("* wrote {markdown:?}");
+
+    let blocks = code_blocks(&html);
+    for (k, block) in blocks.iter().enumerate() {
+        let lines = block.lines().collect::<Vec<_>>();
+        println!("[{}] {} line(s)", k + 1, lines.len());
+        for line in lines.iter().take(3) {
+            println!("    {line}");
+        }
+        if lines.len() > 3 {
+            println!("    ...");
+        }
+    }
+
+    for (n, k) in chosen.into_iter().enumerate() {
+        let block = k
+            .checked_sub(1)
+            .and_then(|i| blocks.get(i))
+            .with_context(|| format!("no code block {k}, there are {}", blocks.len()))?;
+        let path = dir.join(format!("{day}.sample{}", n + 1));
+        if path.exists() && !force {
+            bail!("{path:?} already exists, use --force to overwrite it");
+        }
+        std::fs::write(&path, block)?;
+        println!("* wrote block {k} to {path:?}");
+    }
+    Ok(())
+}
+
+/// The puzzle page cached as `<dir>/<day>.html`, downloading it if missing or `refresh`
+fn puzzle_page(day: &str, dir: &Path, refresh: bool) -> AnyResult<String> {
+    let path = dir.join(format!("{day}.html"));
+    if path.exists() && !refresh {
+        return Ok(std::fs::read_to_string(&path)?);
+    }
+    let client = Client::new(Config::load()?);
+    let html = client.get(&client.config().day_url(day_number(day)?))?;
+    std::fs::write(&path, &html)?;
+    println!("* downloaded {path:?}");
+    Ok(html)
+}
+
+/// Markdown for the page's `<article>`s (the puzzle description), or the whole page
+pub fn to_markdown(html: &str) -> String {
+    let articles = html::articles(html);
+    if articles.is_empty() {
+        html2md::parse_html(html)
+    } else {
+        articles
+            .into_iter()
+            .map(html2md::parse_html)
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+/// Text of every `<pre><code>` block, in page order
+pub fn code_blocks(html: &str) -> Vec<String> {
+    html.split("<pre><code>")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("</code></pre>"))
+        .map(|(block, _)| {
+            let mut text = html_escape::decode_html_entities(&html::strip_tags(block)).into_owned();
+            if !text.ends_with('\n') {
+                text.push('\n');
+            }
+            text
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = "<html><body><main>
+<article class=\"day-desc\"><h2>--- Day 1: Example ---</h2>
+<p>For example:</p>
+<pre><code>L68
+<em>R48</em>
+</code></pre>
+<p>Compare <code>a &lt; b</code>.</p>
+<pre><code>x &amp; y</code></pre>
+</article>
+<p>Answer: <form></form></p>
+</main></body></html>";
+
+    #[test]
+    fn blocks() {
+        assert_eq!(vec!["L68\nR48\n", "x & y\n"], code_blocks(PAGE));
+    }
+
+    #[test]
+    fn markdown() {
+        let markdown = to_markdown(PAGE);
+        assert!(markdown.contains("Day 1: Example"), "{markdown}");
+        assert!(markdown.contains("L68"), "{markdown}");
+        assert!(!markdown.contains("Answer:"), "{markdown}");
+    }
+}
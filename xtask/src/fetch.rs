@@ -0,0 +1,99 @@
+use anyhow::{Context, Result as AnyResult};
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    client::Client,
+    config::{day_number, Config},
+};
+
+pub fn fetch() -> AnyResult<()> {
+    let day = env::args().nth(2).context("require day to fetch")?;
+    let client = Client::new(Config::load()?);
+    match fetch_input(&client, &day, Path::new("input"))? {
+        Fetched::Cached(path) => println!("* using cached {path:?}"),
+        Fetched::Downloaded(path) => println!("* downloaded {path:?}"),
+    }
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Download `day`'s input to `<dir>/<day>.full`, unless it is already there (an empty file, as
+/// left by `setup`, does not count)
+pub fn fetch_input(client: &Client, day: &str, dir: &Path) -> AnyResult<Fetched> {
+    let path = dir.join(format!("{day}.full"));
+    if path.metadata().is_ok_and(|m| m.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+    let input = client.input(day_number(day)?)?;
+    std::fs::create_dir_all(dir)?;
+    // Write then rename, so an interrupted download is never mistaken for a cached input
+    let partial = dir.join(format!("{day}.full.partial"));
+    std::fs::write(&partial, input)?;
+    std::fs::rename(&partial, &path)?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::Stub;
+
+    fn client(base_url: &str) -> Client {
+        Client::new(Config {
+            session: Some("secret".to_string()),
+            base_url: base_url.to_string(),
+            year: 2025,
+            ..Config::default()
+        })
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("xtask-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_once() {
+        let stub = Stub::serve(vec![(200, "1,2,3\n")]);
+        let client = client(&stub.base_url);
+        let dir = temp_dir("fetch");
+
+        let path = dir.join("day3.full");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, "").unwrap();
+        assert_eq!(
+            Fetched::Downloaded(path.clone()),
+            fetch_input(&client, "day3", &dir).unwrap()
+        );
+        assert_eq!("1,2,3\n", std::fs::read_to_string(&path).unwrap());
+        let requests = stub.requests();
+        assert_eq!(1, requests.len());
+        assert!(requests[0].starts_with("GET /2025/day/3/input "));
+        assert!(requests[0].contains("session=secret"));
+
+        assert_eq!(
+            Fetched::Cached(path),
+            fetch_input(&client, "day3", &dir).unwrap()
+        );
+        assert_eq!(1, stub.requests().len());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn http_error() {
+        let stub = Stub::serve(vec![(404, "Not Found")]);
+        let dir = temp_dir("fetch-error");
+        let e = fetch_input(&client(&stub.base_url), "day9", &dir).unwrap_err();
+        assert!(e.to_string().contains("HTTP 404"), "{e}");
+        assert!(!dir.join("day9.full").exists());
+    }
+}
@@ -0,0 +1,24 @@
+/// Contents of each `<article>` element, in page order
+pub fn articles(html: &str) -> Vec<&str> {
+    html.split("<article")
+        .skip(1)
+        .filter_map(|rest| rest.split_once('>'))
+        .filter_map(|(_, rest)| rest.split_once("</article>"))
+        .map(|(article, _)| article)
+        .collect()
+}
+
+/// Text with all markup removed (entities are left encoded)
+pub fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
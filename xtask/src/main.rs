@@ -1,40 +1,41 @@
-use anyhow::{Context, Result as AnyResult};
-use std::{env, path::PathBuf};
+use anyhow::Result as AnyResult;
+use std::env;
+
+mod client;
+mod config;
+mod day;
+mod examples;
+mod fetch;
+mod html;
+mod run_all;
+mod setup;
+#[cfg(test)]
+mod stub;
+mod submit;
+mod watch;
+
+const USAGE: &str = "usage: cargo xtask <command> [args]
+    setup dayN          create a new day from 'template' (see setup --help)
+    fetch dayN          download the day's input
+    examples dayN       save the puzzle as Markdown and its examples as samples
+    submit dayN partP   submit an answer, recording it in the guess ledger
+    watch dayN          rerun the day whenever it changes
+    run-all             run every day and print a status table
+    coverage, vars, ci, powerset, bloat-deps, bloat-time, docs (from xtaskops)";
 
 fn main() -> AnyResult<()> {
-    eprintln!("=-=-=-=-= No documentation as-yet for 'setup'");
     let task = env::args().nth(1);
     match task.as_deref() {
-        Some("setup") => setup(),
+        Some("setup") => setup::setup(),
+        Some("examples") => examples::examples(),
+        Some("fetch") => fetch::fetch(),
+        Some("run-all") => run_all::run_all(),
+        Some("submit") => submit::submit(),
+        Some("watch") => watch::watch(),
+        Some("--help" | "-h" | "help") => {
+            println!("{USAGE}");
+            Ok(())
+        }
         _ => xtaskops::tasks::main(),
     }
 }
-
-fn setup() -> AnyResult<()> {
-    let entry = env::args().nth(2).context("require new workspace entry")?;
-
-    // Copy template to new directory
-    println!("* copy 'template' to '{entry}'");
-    xtaskops::ops::copy_contents("template", &entry, false)?;
-    // Replace 'template' with entry name in Cargo.toml, src/main.rs
-    for filename in ["Cargo.toml", "src/main.rs"]
-        .iter()
-        .map(|name| PathBuf::from(&entry).join(name))
-    {
-        println!("* setup {filename:?}");
-        let contents = std::fs::read_to_string(&filename)?;
-        let contents = contents.replace("template", &entry);
-        std::fs::write(filename, contents)?;
-    }
-    // Add entry to workspace
-    println!("* setup Cargo.toml");
-
-    let workspace_toml_contents = std::fs::read_to_string("Cargo.toml")?;
-    let mut toml = workspace_toml_contents.parse::<toml_edit::DocumentMut>()?;
-    toml["workspace"]["members"]
-        .as_array_mut()
-        .context("read workspace members")?
-        .push(entry);
-    std::fs::write("Cargo.toml", toml.to_string())?;
-    Ok(())
-}
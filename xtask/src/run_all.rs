@@ -0,0 +1,231 @@
+use anyhow::{bail, Context, Result as AnyResult};
+use std::{env, path::Path};
+use utils::{
+    expected::Verdict,
+    report::{self, Outcome, Part, RunReport},
+};
+
+use crate::day;
+
+const USAGE: &str = "usage: cargo xtask run-all [--release] [--readme]
+    --release  build and run each day in release mode
+    --readme   replace the status table in README.md";
+
+/// README.md section holding the Markdown table
+const START: &str = "<!-- run-all:start -->";
+const END: &str = "<!-- run-all:end -->";
+
+/// Run every `dayN` workspace member, then print (and optionally put in the README) a table of
+/// each part's full-input answer, whether it is verified and how long it took
+pub fn run_all() -> AnyResult<()> {
+    let mut release = false;
+    let mut readme = false;
+    for arg in env::args().skip(2) {
+        match arg.as_str() {
+            "--release" => release = true,
+            "--readme" => readme = true,
+            _ => bail!("unknown argument '{arg}'\n{USAGE}"),
+        }
+    }
+    let cargo_args: &[&str] = if release { &["--release"] } else { &[] };
+
+    let mut rows = Vec::new();
+    for day in members(&std::fs::read_to_string("Cargo.toml")?)? {
+        let (_, full) = utils::discover(Path::new("input"), &day)?;
+        if full.is_empty() {
+            rows.extend(status_rows(&day, &Ok(Vec::new())));
+            continue;
+        }
+        println!("* running {day}");
+        let reports = day::run_with(&day, cargo_args, &[]);
+        rows.extend(status_rows(&day, &reports));
+    }
+
+    print!("{}", report::table(&with_heading(&rows)));
+    let answered = rows.iter().filter(|row| row[1] != "-").count();
+    let verified = rows.iter().filter(|row| row[3] == "verified").count();
+    println!("{verified} of {answered} answered parts verified");
+
+    if readme {
+        let contents = std::fs::read_to_string("README.md")?;
+        std::fs::write("README.md", replace_section(&contents, &markdown(&rows)))?;
+        println!("* updated README.md");
+    }
+    Ok(())
+}
+
+/// The workspace's `dayN` members, in day order
+fn members(cargo_toml: &str) -> AnyResult<Vec<String>> {
+    let toml = cargo_toml.parse::<toml_edit::DocumentMut>()?;
+    let mut days = toml["workspace"]["members"]
+        .as_array()
+        .context("read workspace members")?
+        .iter()
+        .filter_map(|member| member.as_str())
+        .filter_map(|member| {
+            let n = member.strip_prefix("day")?.parse::<u32>().ok()?;
+            Some((n, member.to_string()))
+        })
+        .collect::<Vec<_>>();
+    days.sort();
+    Ok(days.into_iter().map(|(_, day)| day).collect())
+}
+
+/// `[day, part, answer, status, time]` for each part of each full input
+fn status_rows(day: &str, reports: &AnyResult<Vec<RunReport>>) -> Vec<[String; 5]> {
+    let row = |part: &str, answer: &str, status: &str, time: &str| {
+        [day, part, answer, status, time].map(str::to_string)
+    };
+    let reports = match reports {
+        Ok(reports) => reports,
+        Err(_) => return vec![row("-", "-", "failed to run", "-")],
+    };
+    let full = reports.iter().filter(|r| r.is_full).collect::<Vec<_>>();
+    if full.is_empty() {
+        return vec![row("-", "-", "no full input", "-")];
+    }
+    let mut rows = Vec::new();
+    for report in full {
+        if report.error.is_some() {
+            rows.push(row("-", "-", "failed to load", "-"));
+            continue;
+        }
+        for p in [Part::One, Part::Two] {
+            let Some(part) = report.parts.iter().find(|r| r.part == p) else {
+                continue;
+            };
+            let answer = match &part.outcome {
+                Outcome::Answer(answer) => answer.as_str(),
+                Outcome::Error(_) => "error",
+                Outcome::Timeout(_) => "TIMEOUT",
+            };
+            let status = match &part.verdict {
+                Verdict::Pass => "verified".to_string(),
+                Verdict::Unknown => "unverified".to_string(),
+                Verdict::Fail { expected } => format!("FAIL, expected {expected}"),
+                Verdict::Rejected { reason } => format!("REJECTED, {reason}"),
+            };
+            rows.push(row(
+                &p.to_string(),
+                answer,
+                &status,
+                &part.timing.to_string(),
+            ));
+        }
+    }
+    rows
+}
+
+fn with_heading(rows: &[[String; 5]]) -> Vec<[String; 5]> {
+    let heading = ["day", "part", "answer", "status", "time"].map(str::to_string);
+    std::iter::once(heading)
+        .chain(rows.iter().cloned())
+        .collect()
+}
+
+fn markdown(rows: &[[String; 5]]) -> String {
+    let mut markdown = String::new();
+    for (i, row) in with_heading(rows).iter().enumerate() {
+        markdown.push_str(&format!("| {} |\n", row.join(" | ")));
+        if i == 0 {
+            markdown.push_str(&format!("|{}\n", " --- |".repeat(row.len())));
+        }
+    }
+    markdown
+}
+
+/// `contents` with the text between the run-all markers replaced by `table`, adding a Status
+/// section (and the markers) at the end if they are missing
+fn replace_section(contents: &str, table: &str) -> String {
+    let section = format!("{START}\n{table}{END}");
+    match (contents.find(START), contents.find(END)) {
+        (Some(start), Some(end)) if start < end => format!(
+            "{}{section}{}",
+            &contents[..start],
+            &contents[end + END.len()..]
+        ),
+        _ => format!("{}\n\n## Status\n{section}\n", contents.trim_end()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use utils::{report::PartReport, timing::Timing};
+
+    #[test]
+    fn workspace_members() {
+        let toml =
+            "[workspace]\nmembers = [\"utils\", \"day10\", \"template\", \"day2\", \"day1\"]";
+        assert_eq!(vec!["day1", "day2", "day10"], members(toml).unwrap());
+    }
+
+    fn part(part: Part, outcome: Outcome, verdict: Verdict) -> PartReport {
+        PartReport {
+            part,
+            outcome,
+            verdict,
+            timing: Timing::default(),
+        }
+    }
+
+    #[test]
+    fn rows_and_markdown() {
+        let full = RunReport {
+            day: "day1".to_string(),
+            filename: "input/day1.full".to_string(),
+            is_full: true,
+            params: BTreeMap::new(),
+            error: None,
+            load: Timing::default(),
+            analyse: Timing::default(),
+            parts: vec![
+                part(Part::One, Outcome::Answer("42".to_string()), Verdict::Pass),
+                part(
+                    Part::Two,
+                    Outcome::Error("oops".to_string()),
+                    Verdict::Unknown,
+                ),
+            ],
+        };
+        let mut sample = full.clone();
+        sample.is_full = false;
+        let rows = status_rows("day1", &Ok(vec![sample.clone(), full]));
+        assert_eq!(
+            vec![
+                ["day1", "part1", "42", "verified", "-"],
+                ["day1", "part2", "error", "unverified", "-"],
+            ],
+            rows
+        );
+        assert_eq!(
+            vec![["day2", "-", "-", "no full input", "-"]],
+            status_rows("day2", &Ok(vec![sample]))
+        );
+        assert_eq!(
+            vec![["day3", "-", "-", "failed to run", "-"]],
+            status_rows("day3", &Err(anyhow::anyhow!("no cmake")))
+        );
+
+        assert_eq!(
+            "| day | part | answer | status | time |\n\
+            | --- | --- | --- | --- | --- |\n\
+            | day1 | part1 | 42 | verified | - |\n",
+            markdown(&rows[..1])
+        );
+    }
+
+    #[test]
+    fn readme_section() {
+        let readme = "# advent\n\nIntro\n";
+        let once = replace_section(readme, "| a |\n");
+        assert_eq!(
+            "# advent\n\nIntro\n\n## Status\n<!-- run-all:start -->\n| a |\n<!-- run-all:end -->\n",
+            once
+        );
+        let twice = replace_section(&once, "| b |\n");
+        assert_eq!(once.replace("| a |", "| b |"), twice);
+    }
+}
@@ -0,0 +1,356 @@
+use anyhow::{bail, Context, Result as AnyResult};
+use std::{env, path::Path};
+
+const USAGE: &str = "usage: cargo xtask setup dayN [--template KIND]
+Create crate dayN from a template and add it to the workspace and to the
+advent runner (src/main.rs), with empty input/dayN.sample and input/dayN.full
+inputs and an input/dayN.sample.expected stub to fill in. Existing crates,
+workspace members and inputs are never overwritten.
+    --template KIND  starting point for parsing the input:
+                     lines     each line in turn (default, 'template')
+                     grid      a FixedGrid<char> ('template-grid')
+                     graph     'node: neighbours...' adjacency list ('template-graph')
+                     sections  blocks separated by blank lines ('template-sections')
+                     numbers   the numbers on each line ('template-numbers')";
+
+/// Template crate for each `--template` kind
+const TEMPLATES: &[(&str, &str)] = &[
+    ("lines", "template"),
+    ("grid", "template-grid"),
+    ("graph", "template-graph"),
+    ("sections", "template-sections"),
+    ("numbers", "template-numbers"),
+];
+
+/// Names cargo will not accept for a package
+const RESERVED: &[&str] = &[
+    "alloc",
+    "as",
+    "async",
+    "await",
+    "break",
+    "const",
+    "continue",
+    "core",
+    "crate",
+    "dyn",
+    "else",
+    "enum",
+    "extern",
+    "false",
+    "fn",
+    "for",
+    "if",
+    "impl",
+    "in",
+    "let",
+    "loop",
+    "match",
+    "mod",
+    "move",
+    "mut",
+    "proc_macro",
+    "pub",
+    "ref",
+    "return",
+    "self",
+    "static",
+    "std",
+    "struct",
+    "super",
+    "test",
+    "trait",
+    "true",
+    "type",
+    "unsafe",
+    "use",
+    "where",
+    "while",
+];
+
+pub fn setup() -> AnyResult<()> {
+    let mut entry = None;
+    let mut kind = "lines".to_string();
+    let mut args = env::args().skip(2);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--help" | "-h" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            "--template" => kind = args.next().context(USAGE)?,
+            _ if entry.is_none() && !arg.starts_with('-') => entry = Some(arg),
+            _ => bail!("unknown argument '{arg}'\n{USAGE}"),
+        }
+    }
+    let entry = entry.with_context(|| format!("require new workspace entry\n{USAGE}"))?;
+    create(Path::new("."), &entry, &kind)
+}
+
+/// Create the crate (from the `kind` template), inputs, workspace member and `advent` registration
+/// for `entry` in the workspace at `root`
+fn create(root: &Path, entry: &str, kind: &str) -> AnyResult<()> {
+    validate_name(entry)?;
+    let template = TEMPLATES
+        .iter()
+        .find(|(k, _)| *k == kind)
+        .map(|(_, template)| *template)
+        .with_context(|| format!("unknown template '{kind}'\n{USAGE}"))?;
+    let workspace_toml = root.join("Cargo.toml");
+    let mut toml = std::fs::read_to_string(&workspace_toml)?.parse::<toml_edit::DocumentMut>()?;
+    let members = toml["workspace"]["members"]
+        .as_array_mut()
+        .context("read workspace members")?;
+    if members.iter().any(|m| m.as_str() == Some(entry)) {
+        bail!("'{entry}' is already a workspace member");
+    }
+    let dir = root.join(entry);
+    if dir.exists() {
+        bail!("{dir:?} already exists");
+    }
+
+    // Copy template to new directory
+    println!("* copy '{template}' to '{entry}'");
+    xtaskops::ops::copy_contents(root.join(template), &dir, false)?;
+    // Replace the template's package (and library) name with entry name in Cargo.toml, src/main.rs
+    let (library, entry_library) = (template.replace('-', "_"), entry.replace('-', "_"));
+    for filename in ["Cargo.toml", "src/main.rs"]
+        .iter()
+        .map(|name| dir.join(name))
+    {
+        println!("* setup {filename:?}");
+        let contents = std::fs::read_to_string(&filename)?;
+        let contents = contents
+            .replace(template, entry)
+            .replace(&library, &entry_library);
+        std::fs::write(filename, contents)?;
+    }
+    // Add entry to workspace
+    println!("* setup Cargo.toml");
+    members.push(entry);
+    let registered = register(root, &mut toml, entry)?;
+    std::fs::write(&workspace_toml, toml.to_string())?;
+    if !registered {
+        println!(
+            "* '{entry}' is not added to the advent runner: add it to the root Cargo.toml \
+             dependencies and the days! list in src/main.rs by hand"
+        );
+    }
+
+    let input = root.join("input");
+    std::fs::create_dir_all(&input)?;
+    for (name, contents) in [
+        (format!("{entry}.sample"), ""),
+        (format!("{entry}.full"), ""),
+        (format!("{entry}.sample.expected"), "part1 =\npart2 =\n"),
+    ] {
+        let path = input.join(name);
+        if path.exists() {
+            println!("* keep existing {path:?}");
+        } else {
+            println!("* create {path:?}");
+            std::fs::write(path, contents)?;
+        }
+    }
+    Ok(())
+}
+
+/// Add day crate `entry` to the root package's dependencies and to the `days!` list in its
+/// src/main.rs, so that `advent` runs it. False if `entry` is not a `dayN` crate or the root has
+/// no such runner.
+fn register(root: &Path, toml: &mut toml_edit::DocumentMut, entry: &str) -> AnyResult<bool> {
+    let main_rs = root.join("src/main.rs");
+    let Some(dependencies) = toml.get_mut("dependencies").and_then(|d| d.as_table_mut()) else {
+        return Ok(false);
+    };
+    if day_number(entry).is_none() || !main_rs.exists() {
+        return Ok(false);
+    }
+    let Some(main) = add_to_days(&std::fs::read_to_string(&main_rs)?, entry) else {
+        return Ok(false);
+    };
+    println!("* setup {main_rs:?}");
+    std::fs::write(&main_rs, main)?;
+    if !dependencies.contains_key(entry) {
+        dependencies[entry] =
+            toml_edit::value(format!("{{path = \"{entry}\"}}").parse::<toml_edit::Value>()?);
+    }
+    Ok(true)
+}
+
+fn day_number(name: &str) -> Option<u32> {
+    name.strip_prefix("day")?.parse().ok()
+}
+
+/// `main` with `entry` added, in day order, to the `DAYS` registry; `None` if there is none
+fn add_to_days(main: &str, entry: &str) -> Option<String> {
+    const START: &str = "const DAYS: &[(&str, Runner)] =";
+    let start = main.find(START)?;
+    let open = start + main[start..].find("days!(")?;
+    let end = open + main[open..].find(");")? + 2;
+    let mut days = main[open + "days!(".len()..end - 2]
+        .split(',')
+        .map(str::trim)
+        .filter(|day| !day.is_empty())
+        .collect::<Vec<_>>();
+    if !days.contains(&entry) {
+        days.push(entry);
+    }
+    days.sort_by_key(|day| day_number(day));
+    // Laid out as rustfmt would
+    let line = format!("    days!({},);", days.join(", "));
+    let registry = if line.len() <= 100 {
+        format!("{START}\n{line}")
+    } else {
+        // As many to a row as fit
+        let mut rows = String::new();
+        let mut row = String::from("   ");
+        for day in days {
+            if row.len() > 3 && row.len() + day.len() + 2 > 100 {
+                rows.push_str(&row);
+                rows.push('\n');
+                row = String::from("   ");
+            }
+            row.push_str(&format!(" {day},"));
+        }
+        format!("{START} days!(\n{rows}{row}\n);")
+    };
+    Some(format!("{}{registry}{}", &main[..start], &main[end..]))
+}
+
+/// Reject names cargo would not accept as a package name
+fn validate_name(name: &str) -> AnyResult<()> {
+    let mut chars = name.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        bail!(
+            "invalid crate name '{name}': use letters, digits, '-' and '_', starting with a letter"
+        );
+    }
+    if RESERVED.contains(&name) || name.starts_with("template") {
+        bail!("invalid crate name '{name}': the name is reserved");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names() {
+        assert!(validate_name("day7").is_ok());
+        assert!(validate_name("day7-alt_2").is_ok());
+        for name in [
+            "",
+            "7day",
+            "day 7",
+            "day7/",
+            "../day7",
+            "test",
+            "template",
+            "template-grid",
+        ] {
+            assert!(validate_name(name).is_err(), "{name}");
+        }
+    }
+
+    #[test]
+    fn registers_day() {
+        let main = "fn main() {}\n\nconst DAYS: &[(&str, Runner)] =\n    days!(day1, day3,);\n";
+        assert_eq!(
+            "fn main() {}\n\nconst DAYS: &[(&str, Runner)] =\n    days!(day1, day2, day3,);\n",
+            add_to_days(main, "day2").unwrap()
+        );
+        assert_eq!(main, add_to_days(main, "day3").unwrap());
+        assert_eq!(None, add_to_days("fn main() {}\n", "day2"));
+
+        // Once the list no longer fits on one line, rustfmt fills rows of up to 100 chars
+        let days = (1..=25).map(|n| format!("day{n}")).collect::<Vec<_>>();
+        let main = format!(
+            "const DAYS: &[(&str, Runner)] =\n    days!({},);\n",
+            days[..14].join(", ")
+        );
+        assert_eq!(
+            format!(
+                "const DAYS: &[(&str, Runner)] = days!(\n    {},\n);\n",
+                days[..15].join(", ")
+            ),
+            add_to_days(&main, "day15").unwrap()
+        );
+        let main = days[14..]
+            .iter()
+            .fold(main, |main, day| add_to_days(&main, day).unwrap());
+        assert_eq!(
+            format!(
+                "const DAYS: &[(&str, Runner)] = days!(\n    {},\n    {},\n);\n",
+                days[..15].join(", "),
+                days[15..].join(", ")
+            ),
+            main
+        );
+    }
+
+    #[test]
+    fn creates_day() {
+        let root = env::temp_dir().join(format!("xtask-setup-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        for template in ["template", "template-grid"] {
+            std::fs::create_dir_all(root.join(template).join("src")).unwrap();
+            std::fs::write(
+                root.join(template).join("Cargo.toml"),
+                format!("name = \"{template}\"\n"),
+            )
+            .unwrap();
+            std::fs::write(
+                root.join(template).join("src/main.rs"),
+                format!("use {}::Solution;\n", template.replace('-', "_")),
+            )
+            .unwrap();
+        }
+        std::fs::create_dir_all(root.join("day1")).unwrap();
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[dependencies]\nday1 = {path = \"day1\"}\n\n\
+             [workspace]\nmembers = [\"template\", \"day1\"]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("src/main.rs"),
+            "const DAYS: &[(&str, Runner)] =\n    days!(day1,);\n",
+        )
+        .unwrap();
+
+        create(&root, "day2", "lines").unwrap();
+        let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap();
+        assert_eq!("name = \"day2\"\n", read("day2/Cargo.toml"));
+        assert_eq!("use day2::Solution;\n", read("day2/src/main.rs"));
+        assert!(read("Cargo.toml").contains("\"day1\", \"day2\"]"));
+        assert_eq!("", read("input/day2.full"));
+        assert_eq!("part1 =\npart2 =\n", read("input/day2.sample.expected"));
+        assert!(read("Cargo.toml").contains("day2 = {path = \"day2\"}"));
+        assert_eq!(
+            "const DAYS: &[(&str, Runner)] =\n    days!(day1, day2,);\n",
+            read("src/main.rs")
+        );
+
+        create(&root, "extra", "lines").unwrap();
+        assert!(!read("Cargo.toml").contains("extra = "));
+        assert!(!read("src/main.rs").contains("extra"));
+
+        create(&root, "day3", "grid").unwrap();
+        assert_eq!("name = \"day3\"\n", read("day3/Cargo.toml"));
+        assert_eq!("use day3::Solution;\n", read("day3/src/main.rs"));
+
+        let e = create(&root, "day4", "maze").unwrap_err();
+        assert!(e.to_string().contains("unknown template"), "{e}");
+        let e = create(&root, "day2", "lines").unwrap_err();
+        assert!(e.to_string().contains("already a workspace member"), "{e}");
+        std::fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = []\n").unwrap();
+        let e = create(&root, "day1", "lines").unwrap_err();
+        assert!(e.to_string().contains("already exists"), "{e}");
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
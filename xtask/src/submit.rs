@@ -0,0 +1,323 @@
+use anyhow::{bail, Context, Result as AnyResult};
+use std::{
+    env,
+    fmt::Display,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use utils::{
+    expected::Verdict,
+    ledger::{Guess, Ledger, Status},
+    report::{Outcome, Part},
+};
+
+use crate::{
+    client::Client,
+    config::{day_number, Config},
+    day, html,
+};
+
+/// Cooldown after a wrong answer when the response does not say how long to wait
+const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+pub fn submit() -> AnyResult<()> {
+    let day = env::args().nth(2).context("require day to submit")?;
+    let part = env::args()
+        .nth(3)
+        .context("require part to submit")?
+        .parse::<Part>()?;
+    let answer = match env::args().nth(4) {
+        Some(answer) => answer,
+        None => computed_answer(&day, part)?,
+    };
+    let client = Client::new(Config::load()?);
+    let response = submit_answer(
+        &client,
+        &day,
+        part,
+        &answer,
+        Path::new("input"),
+        SystemTime::now(),
+    )?;
+    println!("* {day} {part} {answer}: {response}");
+    Ok(())
+}
+
+/// The answer the day currently gives for its full input
+fn computed_answer(day: &str, part: Part) -> AnyResult<String> {
+    let reports = day::run(day, &[])?;
+    let answers = reports
+        .iter()
+        .filter(|r| r.is_full)
+        .flat_map(|r| r.parts.iter())
+        .filter(|p| p.part == part)
+        .collect::<Vec<_>>();
+    match answers[..] {
+        [answer] => match &answer.outcome {
+            Outcome::Answer(answer) => Ok(answer.clone()),
+            _ => bail!("{day} {part} did not produce an answer"),
+        },
+        [] => bail!("no full input answer for {day} {part}"),
+        _ => bail!("{day} has several full inputs, give the answer explicitly"),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Correct,
+    Incorrect {
+        status: Status,
+        wait: Duration,
+    },
+    /// Submitted too soon after a previous answer
+    Wait(Duration),
+    AlreadySolved,
+    Unrecognised(String),
+}
+
+impl Display for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Response::Correct => write!(f, "correct"),
+            Response::Incorrect { status, wait } => {
+                write!(f, "{status}, wait {}s before trying again", wait.as_secs())
+            }
+            Response::Wait(wait) => write!(f, "submitted too soon, wait {}s", wait.as_secs()),
+            Response::AlreadySolved => write!(f, "already solved"),
+            Response::Unrecognised(text) => write!(f, "unrecognised response: {text}"),
+        }
+    }
+}
+
+/// Submit `answer`, unless the ledger in `dir` already rules it out or a cooldown is running,
+/// recording the outcome in the ledger
+pub fn submit_answer(
+    client: &Client,
+    day: &str,
+    part: Part,
+    answer: &str,
+    dir: &Path,
+    now: SystemTime,
+) -> AnyResult<Response> {
+    let cooldown = dir.join("submit.cooldown");
+    if let Some(until) = read_cooldown(&cooldown)? {
+        if let Ok(left) = until.duration_since(now) {
+            bail!(
+                "still cooling down, wait {}s before submitting again",
+                left.as_secs() + 1
+            );
+        }
+    }
+    let ledger_path = dir.join(format!("{day}.ledger"));
+    let ledger_path = ledger_path.to_string_lossy();
+    match Ledger::load(&ledger_path)?.check(part, answer) {
+        Verdict::Pass => bail!("{day} {part} was already solved with {answer}"),
+        Verdict::Fail { expected } => bail!("{day} {part} was already solved with {expected}"),
+        Verdict::Rejected { reason } => bail!("not submitting {answer}: {reason}"),
+        Verdict::Unknown => {}
+    }
+
+    let url = format!("{}/answer", client.config().day_url(day_number(day)?));
+    let level = match part {
+        Part::One => "1",
+        Part::Two => "2",
+    };
+    let response =
+        parse_response(&client.post_form(&url, &[("level", level), ("answer", answer)])?);
+    std::fs::create_dir_all(dir)?;
+    let record = |status| {
+        let guess = Guess {
+            part,
+            status,
+            answer: answer.to_string(),
+        };
+        Ledger::record(&ledger_path, &guess)
+    };
+    match &response {
+        Response::Correct => record(Status::Correct)?,
+        Response::Incorrect { status, wait } => {
+            record(*status)?;
+            write_cooldown(&cooldown, now + *wait)?;
+        }
+        Response::Wait(wait) => write_cooldown(&cooldown, now + *wait)?,
+        Response::AlreadySolved | Response::Unrecognised(_) => {}
+    }
+    Ok(response)
+}
+
+fn read_cooldown(path: &Path) -> AnyResult<Option<SystemTime>> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => {
+            let secs = contents
+                .trim()
+                .parse()
+                .with_context(|| format!("reading {path:?}"))?;
+            Ok(Some(UNIX_EPOCH + Duration::from_secs(secs)))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| format!("reading {path:?}")),
+    }
+}
+
+fn write_cooldown(path: &Path, until: SystemTime) -> AnyResult<()> {
+    let secs = until.duration_since(UNIX_EPOCH)?.as_secs_f64().ceil() as u64;
+    std::fs::write(path, format!("{secs}\n"))?;
+    Ok(())
+}
+
+/// Classify the page returned for a submitted answer
+pub fn parse_response(html: &str) -> Response {
+    let text = article_text(html);
+    let lower = text.to_lowercase();
+    if lower.contains("that's the right answer") {
+        Response::Correct
+    } else if lower.contains("not the right answer") {
+        let status = if lower.contains("too high") {
+            Status::TooHigh
+        } else if lower.contains("too low") {
+            Status::TooLow
+        } else {
+            Status::Wrong
+        };
+        let wait = wait_before_retry(&lower).unwrap_or(DEFAULT_WAIT);
+        Response::Incorrect { status, wait }
+    } else if lower.contains("answer too recently") {
+        Response::Wait(time_left(&lower).unwrap_or(DEFAULT_WAIT))
+    } else if lower.contains("right level") {
+        Response::AlreadySolved
+    } else {
+        Response::Unrecognised(text)
+    }
+}
+
+/// Text of the page's `<article>` (or the whole page), without markup
+fn article_text(page: &str) -> String {
+    let article = html::articles(page).first().copied().unwrap_or(page);
+    html::strip_tags(article)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// e.g. "please wait one minute before trying again", "please wait 5 minutes ..."
+fn wait_before_retry(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+    let count = match words.next()? {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(Duration::from_secs(count * 60)),
+        unit if unit.starts_with("second") => Some(Duration::from_secs(count)),
+        _ => None,
+    }
+}
+
+/// e.g. "you have 1m 2s left to wait"
+fn time_left(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, left) = before.rsplit_once("you have ")?;
+    let mut secs = 0;
+    for part in left.split_whitespace() {
+        let (value, unit) = part.split_at(part.len().checked_sub(1)?);
+        let value = value.parse::<u64>().ok()?;
+        secs += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::Stub;
+
+    const TOO_HIGH: &str =
+        "<main><article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, make sure you're using the full input data. Please wait one minute before \
+        trying again. <a href=\"/2025/day/9\">[Return to Day 9]</a></p></article></main>";
+    const CORRECT: &str =
+        "<article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article>";
+    const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 1m 2s left to wait.</p></article>";
+
+    #[test]
+    fn responses() {
+        assert_eq!(Response::Correct, parse_response(CORRECT));
+        assert_eq!(
+            Response::Incorrect {
+                status: Status::TooHigh,
+                wait: Duration::from_secs(60)
+            },
+            parse_response(TOO_HIGH)
+        );
+        assert_eq!(
+            Response::Incorrect {
+                status: Status::Wrong,
+                wait: Duration::from_secs(300)
+            },
+            parse_response(
+                "<article>That's not the right answer. Please wait 5 minutes.</article>"
+            )
+        );
+        assert_eq!(
+            Response::Wait(Duration::from_secs(62)),
+            parse_response(TOO_SOON)
+        );
+        assert_eq!(
+            Response::AlreadySolved,
+            parse_response(
+                "<article><p>You don't seem to be solving the right level.</p></article>"
+            )
+        );
+        assert!(matches!(
+            parse_response("<p>Maintenance</p>"),
+            Response::Unrecognised(_)
+        ));
+    }
+
+    fn setup(name: &str, responses: Vec<(u16, &str)>) -> (Stub, Client, std::path::PathBuf) {
+        let stub = Stub::serve(responses);
+        let client = Client::new(Config {
+            session: Some("secret".to_string()),
+            base_url: stub.base_url.clone(),
+            ..Config::default()
+        });
+        let dir = env::temp_dir().join(format!("xtask-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        (stub, client, dir)
+    }
+
+    #[test]
+    fn records_and_cools_down() {
+        let (stub, client, dir) = setup("submit", vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let now = UNIX_EPOCH + Duration::from_secs(1_000_000);
+
+        let response = submit_answer(&client, "day9", Part::Two, "500", &dir, now).unwrap();
+        assert!(matches!(response, Response::Incorrect { .. }));
+        let requests = stub.requests();
+        assert!(requests[0].starts_with("POST /2025/day/9/answer "));
+        assert!(requests[0].ends_with("level=2&answer=500"));
+
+        // Refused locally: cooling down, then a known too-high answer
+        let e = submit_answer(&client, "day9", Part::Two, "400", &dir, now).unwrap_err();
+        assert!(e.to_string().contains("cooling down"), "{e}");
+        let later = now + Duration::from_secs(61);
+        let e = submit_answer(&client, "day9", Part::Two, "600", &dir, later).unwrap_err();
+        assert!(e.to_string().contains("too-high guess 500"), "{e}");
+        assert_eq!(1, stub.requests().len());
+
+        let response = submit_answer(&client, "day9", Part::Two, "400", &dir, later).unwrap();
+        assert_eq!(Response::Correct, response);
+        let ledger = std::fs::read_to_string(dir.join("day9.ledger")).unwrap();
+        assert_eq!("part2 too-high 500\npart2 correct 400\n", ledger);
+        let e = submit_answer(&client, "day9", Part::Two, "400", &dir, later).unwrap_err();
+        assert!(e.to_string().contains("already solved"), "{e}");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
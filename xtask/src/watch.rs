@@ -0,0 +1,113 @@
+use anyhow::{bail, Context, Result as AnyResult};
+use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode, DebounceEventResult};
+use std::{
+    collections::BTreeMap,
+    env,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::{Duration, SystemTime},
+};
+use utils::report::{self, RunReport};
+
+use crate::day;
+
+/// Rebuild and rerun `day` whenever its crate or inputs change, showing which answers changed
+pub fn watch() -> AnyResult<()> {
+    let day = env::args().nth(2).context("require day to watch")?;
+    let args = env::args().skip(3).collect::<Vec<_>>();
+    if !Path::new(&day).join("Cargo.toml").exists() {
+        bail!("no day crate '{day}'");
+    }
+    std::fs::create_dir_all("input")?;
+
+    let (tx, rx) = mpsc::channel::<DebounceEventResult>();
+    let mut debouncer = new_debouncer(Duration::from_millis(250), tx)?;
+    debouncer
+        .watcher()
+        .watch(Path::new(&day), RecursiveMode::Recursive)?;
+    debouncer
+        .watcher()
+        .watch(Path::new("input"), RecursiveMode::NonRecursive)?;
+
+    println!("* watching '{day}' and 'input/{day}.*' (ctrl-c to stop)");
+    let mut seen = snapshot(&day)?;
+    let mut previous = run(&day, &args);
+    if let Some(reports) = &previous {
+        print!("{}", report::summary(reports));
+    }
+    for events in rx {
+        events?;
+        // Building and running the day reads the watched files too, so only act on real changes
+        let current = snapshot(&day)?;
+        let changed = current
+            .iter()
+            .filter(|(path, modified)| seen.get(*path) != Some(modified))
+            .map(|(path, _)| path.display().to_string())
+            .chain(
+                seen.keys()
+                    .filter(|path| !current.contains_key(*path))
+                    .map(|path| format!("{} (removed)", path.display())),
+            )
+            .collect::<Vec<_>>();
+        if changed.is_empty() {
+            continue;
+        }
+        seen = current;
+        println!("* changed: {}", changed.join(", "));
+        let Some(reports) = run(&day, &args) else {
+            continue;
+        };
+        match &previous {
+            None => print!("{}", report::summary(&reports)),
+            Some(before) => {
+                let changes = report::changes(before, &reports);
+                if changes.is_empty() {
+                    println!("* no answers changed");
+                }
+                for change in changes {
+                    println!("  {change}");
+                }
+            }
+        }
+        let num_failed = reports.iter().map(RunReport::num_failed).sum::<usize>();
+        if num_failed > 0 {
+            println!("* {num_failed} answer(s) did not match expected");
+        }
+        previous = Some(reports);
+    }
+    Ok(())
+}
+
+/// Modification time of every file in the day crate and of its inputs
+fn snapshot(day: &str) -> AnyResult<BTreeMap<PathBuf, SystemTime>> {
+    let mut files = BTreeMap::new();
+    let mut dirs = vec![PathBuf::from(day)];
+    while let Some(dir) = dirs.pop() {
+        // Editors' temporary files can vanish mid-scan, so skip anything unreadable
+        for entry in std::fs::read_dir(dir)?.map_while(Result::ok) {
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                dirs.push(entry.path());
+            } else if let Ok(modified) = metadata.modified() {
+                files.insert(entry.path(), modified);
+            }
+        }
+    }
+    let prefix = format!("{day}.");
+    for entry in std::fs::read_dir("input")?.map_while(Result::ok) {
+        if entry.file_name().to_string_lossy().starts_with(&prefix) {
+            if let Ok(modified) = entry.metadata().and_then(|m| m.modified()) {
+                files.insert(entry.path(), modified);
+            }
+        }
+    }
+    Ok(files)
+}
+
+fn run(day: &str, args: &[String]) -> Option<Vec<RunReport>> {
+    day::run(day, args)
+        .inspect_err(|e| println!("* {e:#}"))
+        .ok()
+}